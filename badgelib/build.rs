@@ -0,0 +1,189 @@
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use rusttype::{Font, Scale, point};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+
+fn write_and_format(out_file: &str, code: &str) {
+  let code = format!("// This file is generated by build.rs\n{}\n", code);
+  fs::write(out_file, code).unwrap();
+  let _ = std::process::Command::new("rustfmt")
+    .arg("--")
+    .arg(out_file)
+    .status()
+    .expect("Failed to run cargo fmt");
+}
+
+fn is_no_file(filepath: &str) -> Option<&str> {
+  match fs::metadata(filepath).map(|m| m.is_file()).unwrap_or(false) {
+    false => Some(filepath),
+    true => None,
+  }
+}
+
+// MARK: Font Width
+
+fn load_font() -> Font<'static> {
+  let font_path = "/System/Library/Fonts/Supplemental/Verdana.ttf";
+  let font_data = std::fs::read(font_path).expect("Failed to read font file");
+  Font::try_from_vec(font_data).expect("Failed to parse font data")
+}
+
+// https://gitlab.redox-os.org/redox-os/rusttype/-/blob/d2b6874c/dev/examples/image.rs
+// https://gitlab.redox-os.org/redox-os/rusttype/-/blob/d2b6874c/dev/examples/ascii.rs
+fn calc_width(font: &Font, text: &str, size: f32) -> f32 {
+  font
+    .layout(text, Scale { x: size * 1.25, y: size }, point(0.0, 0.0))
+    .map(|g| g.position().x + g.unpositioned().h_metrics().advance_width)
+    .last()
+    .unwrap_or(0.0)
+}
+
+fn generate_width(outfile: &str) {
+  let size = 110.0;
+  let font = load_font();
+
+  let mut widths: Vec<f32> = vec![];
+  for x in 0..2u32.pow(13) {
+    if x <= 32 || x == 127 {
+      widths.push(0.0);
+    } else {
+      let ch = char::from_u32(x).unwrap();
+      let cw = calc_width(&font, &ch.to_string(), size);
+      widths.push(cw);
+    }
+  }
+
+  let code = format!("pub(crate) static WIDTHS: [f32; 8192] = {:?};", widths);
+  write_and_format(outfile, &code);
+}
+
+// MARK: Icons
+
+fn get_files_of_kind(base_dir: &str, kind: &str) -> Vec<String> {
+  fs::read_dir(base_dir)
+    .unwrap()
+    .filter_map(|x| match x {
+      Err(_) => None,
+      Ok(x) => {
+        if !x.file_type().unwrap().is_file() {
+          return None;
+        }
+
+        let path = x.path();
+        if path.extension().unwrap_or_default() != kind {
+          return None;
+        }
+
+        Some(path.to_str().unwrap().to_string())
+      }
+    })
+    .collect()
+}
+
+// Same as `titleToSlug` of simple-icons, but only ASCII letters are kept (no NFD normalization),
+// icons with such titles have explicit `slug` in data file anyway
+fn title_to_slug(title: &str) -> String {
+  let pairs = [("+", "plus"), (".", "dot"), ("&", "and"), ("đ", "d"), ("ħ", "h"), ("ı", "i")];
+  let pairs = pairs.iter().chain(&[("ĸ", "k"), ("ŀ", "l"), ("ł", "l"), ("ß", "ss"), ("ŧ", "t")]);
+
+  let mut slug = title.to_lowercase();
+  for (from, to) in pairs {
+    slug = slug.replace(from, to);
+  }
+
+  slug.chars().filter(|c| c.is_ascii_alphanumeric()).collect()
+}
+
+// Brand color, title and aliases of each icon from `simple-icons.json` (file was moved from
+// `_data` to `data` and changed from `{"icons": [..]}` to plain list between versions)
+fn load_icons_data(base_dir: &str) -> Vec<serde_json::Value> {
+  let path = ["data", "_data"].iter().map(|x| format!("{base_dir}/{x}/simple-icons.json"));
+  let path = path.into_iter().find(|x| fs::metadata(x).is_ok()).expect("no simple-icons.json");
+
+  let data: serde_json::Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+  match data {
+    serde_json::Value::Array(x) => x,
+    x => x["icons"].as_array().cloned().unwrap_or_default(),
+  }
+}
+
+fn generate_icons(outfile: &str) {
+  let base_dir = "../vendor/simple-icons";
+  let icons: Vec<(String, String)> = get_files_of_kind(&format!("{base_dir}/icons"), "svg")
+    .into_par_iter()
+    .map(|x| {
+      let name = x.split('/').next_back().unwrap().split('.').next().unwrap().to_string();
+      let data = fs::read_to_string(x).unwrap();
+      let data = data.split("<path d=\"").last().unwrap().split("\"").next().unwrap();
+      (name, data.to_string())
+    })
+    .collect();
+
+  let mut meta = HashMap::new(); // slug -> (hex, title)
+  let mut aliases = BTreeMap::new(); // alias slug -> slug
+  for x in load_icons_data(base_dir) {
+    let title = x["title"].as_str().unwrap_or_default();
+    let slug = x["slug"].as_str().map(|x| x.to_string()).unwrap_or_else(|| title_to_slug(title));
+    let hex = x["hex"].as_str().unwrap_or_default().to_uppercase();
+
+    // titles can differ from slug, e.g. "Visual Studio Code" or localized names
+    let aka = x["aliases"]["aka"].as_array().into_iter().flatten();
+    let loc = x["aliases"]["loc"].as_object().into_iter().flat_map(|x| x.values());
+    for alias in aka.chain(loc).filter_map(|x| x.as_str()).chain([title]) {
+      let alias = title_to_slug(alias);
+      if !alias.is_empty() && alias != slug {
+        aliases.entry(alias).or_insert(slug.clone());
+      }
+    }
+
+    meta.insert(slug, (hex, title.to_string()));
+  }
+
+  let names = icons.iter().map(|(name, _)| name.as_str()).collect::<HashSet<_>>();
+  aliases.retain(|alias, slug| names.contains(slug.as_str()) && !names.contains(alias.as_str()));
+
+  let code = icons
+    .iter()
+    .map(|(name, data)| {
+      let (hex, title) = meta.get(name).cloned().unwrap_or_default();
+      format!(
+        "  \"{name}\" => SimpleIcon {{ path: r###\"{data}\"###, hex: \"{hex}\", title: r###\"{title}\"### }},"
+      )
+    })
+    .collect::<Vec<String>>()
+    .join("\n");
+
+  let aliases = aliases
+    .iter()
+    .map(|(alias, slug)| format!("  \"{alias}\" => \"{slug}\","))
+    .collect::<Vec<String>>()
+    .join("\n");
+
+  let code = format!(
+    "use super::icons::SimpleIcon;\n\n\
+    pub(crate) static ICONS: phf::Map<&'static str, SimpleIcon> = phf::phf_map! {{\n\
+    {code}\n\
+    }};\n\n\
+    pub(crate) static ALIASES: phf::Map<&'static str, &'static str> = phf::phf_map! {{\n\
+    {aliases}\n\
+    }};",
+  );
+
+  write_and_format(outfile, &code);
+}
+
+// MARK: Main
+
+fn main() {
+  println!("cargo::rerun-if-changed=build.rs");
+  println!("cargo::rerun-if-changed=src/_width.rs");
+  println!("cargo::rerun-if-changed=src/_icons.rs");
+
+  if let Some(outfile) = is_no_file("src/_width.rs") {
+    generate_width(outfile)
+  }
+
+  if let Some(outfile) = is_no_file("src/_icons.rs") {
+    generate_icons(outfile)
+  }
+}
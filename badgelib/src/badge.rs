@@ -0,0 +1,686 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use chrono::{DateTime, Utc};
+use maud::html;
+
+use super::Color;
+use super::icons::{brand_color, get_icon};
+use super::raster::{to_png, to_webp};
+use super::thresholds::Thresholds;
+use super::utils::{cacl_width, for_date, millify, millify_iec, render_stars, to_min_ver};
+
+pub type Res<T = ()> = anyhow::Result<T>;
+pub type Dict = HashMap<String, String>;
+
+// Server-wide defaults, applied when query params are not set
+#[derive(Debug, Clone)]
+pub struct Defaults {
+  pub style: BadgeStyle,
+  pub label_color: Color,
+  pub icon_color: Option<Color>, // depends on style if not set
+  pub cache: u32,
+  pub max_scale: f32,
+  pub max_radius: u8,
+}
+
+impl Default for Defaults {
+  fn default() -> Self {
+    Self {
+      style: BadgeStyle::Flat,
+      label_color: Color::DefaultLabel,
+      icon_color: None,
+      cache: 86400, // 24 hours
+      max_scale: 8.0,
+      max_radius: 12,
+    }
+  }
+}
+
+static DEFAULTS: OnceLock<Defaults> = OnceLock::new();
+
+impl Defaults {
+  pub fn set(defaults: Defaults) {
+    let _ = DEFAULTS.set(defaults);
+  }
+
+  pub fn get() -> &'static Defaults {
+    DEFAULTS.get_or_init(Defaults::default)
+  }
+
+  pub(crate) fn icon_color(&self, style: &BadgeStyle) -> Color {
+    // social badges have light background, so default icon color should be dark
+    let fallback = if *style == BadgeStyle::Social { "333" } else { "fff" };
+    self.icon_color.clone().unwrap_or(Color::Hex(fallback.to_string()))
+  }
+}
+
+fn qs_first(qs: &Dict, opts: &[&str]) -> Option<String> {
+  opts.iter().find_map(|k| qs.get(*k).map(|v| v.to_string()))
+}
+
+// `brand` is color of icon from simple-icons, unknown icon falls back to default
+fn qs_color(qs: &Dict, opts: &[&str], icon: Option<&str>) -> Option<Color> {
+  let color = qs_first(qs, opts)?;
+  match color.eq_ignore_ascii_case("brand") {
+    true => brand_color(icon?),
+    false => Color::from_str(&color).ok(),
+  }
+}
+
+// Explicit `color` wins, then `thresholds` query param, then builder default
+fn value_color(qs: &Dict, color: Color, value: f64, default: Color) -> Color {
+  match color {
+    Color::DefaultValue => Thresholds::from_qs(qs).map_or(default, |x| x.color(value)),
+    _ => color,
+  }
+}
+
+pub enum DlPeriod {
+  Weekly,
+  Monthly,
+  Yearly,
+  Total,
+}
+
+#[derive(Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub enum BadgeStyle {
+  #[default]
+  Flat,
+  FlatSquare,
+  Plastic,
+  ForTheBadge,
+  Social,
+}
+
+impl BadgeStyle {
+  pub fn parse(s: &str) -> Option<Self> {
+    match s.to_lowercase().as_str() {
+      "flat" => Some(BadgeStyle::Flat),
+      "flatsquare" | "flat-square" => Some(BadgeStyle::FlatSquare),
+      "forthebadge" | "for-the-badge" => Some(BadgeStyle::ForTheBadge),
+      "plastic" => Some(BadgeStyle::Plastic),
+      "social" => Some(BadgeStyle::Social),
+      _ => None,
+    }
+  }
+
+  pub(crate) fn default_radius(&self) -> u8 {
+    match self {
+      BadgeStyle::Flat => 3,
+      BadgeStyle::Plastic => 4,
+      BadgeStyle::Social => 3,
+      _ => 0,
+    }
+  }
+
+  fn metrics(&self) -> StyleMetrics {
+    match self {
+      BadgeStyle::Flat | BadgeStyle::FlatSquare => StyleMetrics::default(),
+      BadgeStyle::Social => StyleMetrics { bold: true, shadow: false, ..Default::default() },
+      BadgeStyle::Plastic => StyleMetrics { height: 18.0, ..Default::default() },
+      // https://github.com/badges/shields/blob/master/badge-maker/lib/badge-renderers.js
+      BadgeStyle::ForTheBadge => StyleMetrics {
+        height: 28.0,
+        pad: 1.1,
+        gap: 1.1,
+        spacing: 0.125,
+        bold: true,
+        upper: true,
+        shadow: false,
+      },
+    }
+  }
+}
+
+// Geometry of a style, relative to the font size (except height, which is in px)
+struct StyleMetrics {
+  height: f32,
+  pad: f32,
+  gap: f32,
+  spacing: f32,
+  bold: bool,
+  upper: bool,
+  shadow: bool,
+}
+
+impl Default for StyleMetrics {
+  fn default() -> Self {
+    let pad = 0.5;
+    StyleMetrics {
+      height: 20.0,
+      pad,
+      gap: pad / 1.5,
+      spacing: 0.0,
+      bold: false,
+      upper: false,
+      shadow: true,
+    }
+  }
+}
+
+impl StyleMetrics {
+  // Verdana Bold is about 13% wider than regular, there is no separate width table for it
+  fn text_width(&self, text: &str, fz: f32) -> f32 {
+    let width = cacl_width(text) * if self.bold { 1.13 } else { 1.0 };
+    width + self.spacing * fz * text.chars().count() as f32
+  }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Serialize)]
+pub enum BadgeFormat {
+  Svg,
+  Json,
+  Png,
+  Webp,
+}
+
+impl BadgeFormat {
+  pub fn parse(s: &str) -> Self {
+    match s.to_lowercase().as_str() {
+      "json" => BadgeFormat::Json,
+      "png" => BadgeFormat::Png,
+      "webp" => BadgeFormat::Webp,
+      _ => BadgeFormat::Svg,
+    }
+  }
+
+  pub fn mime(&self) -> &'static str {
+    match self {
+      BadgeFormat::Svg => "image/svg+xml",
+      BadgeFormat::Json => "application/json",
+      BadgeFormat::Png => "image/png",
+      BadgeFormat::Webp => "image/webp",
+    }
+  }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Segment {
+  pub text: String,
+  pub color: Color,
+}
+
+impl Segment {
+  // Format: `text:color,text:color`, color is optional (label color used by default)
+  fn parse_list(s: &str) -> Vec<Segment> {
+    s.split(',')
+      .filter(|x| !x.trim().is_empty())
+      .map(|x| {
+        let parts = x.rsplit_once(':');
+        match parts.and_then(|(text, color)| Some((text, Color::from_str(color).ok()?))) {
+          Some((text, color)) => Segment { text: text.to_string(), color },
+          None => Segment { text: x.to_string(), color: Color::DefaultLabel },
+        }
+      })
+      .collect()
+  }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct Badge {
+  pub llabel: Option<String>,
+  pub lcolor: Color,
+  pub rlabel: String,
+  pub rcolor: Color,
+  pub segments: Vec<Segment>, // extra segments after value
+  pub icon: Option<String>,
+  pub icon_color: Color,
+  pub style: BadgeStyle,
+  pub radius: u8,
+  pub scale: f32,
+  pub cache: u32,
+  pub format: BadgeFormat,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub stale: Option<DateTime<Utc>>, // time of last successful fetch, if upstream failed
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub error: Option<String>, // kind of error for error badges, e.g. `not_found`
+}
+
+impl Badge {
+  pub fn new(label: &str, value: &str, value_color: Color) -> Badge {
+    let d = Defaults::get();
+    Badge {
+      llabel: Some(label.to_string()),
+      lcolor: d.label_color.clone(),
+      rlabel: value.to_string(),
+      rcolor: value_color,
+      segments: vec![],
+      icon: None,
+      icon_color: d.icon_color(&d.style),
+      style: d.style.clone(),
+      radius: d.style.default_radius().min(d.max_radius),
+      scale: 1.0,
+      cache: d.cache,
+      format: BadgeFormat::Svg,
+      stale: None,
+      error: None,
+    }
+  }
+
+  pub fn from_qs(qs: &Dict) -> Res<Badge> {
+    let d = Defaults::get();
+    let icon = qs_first(qs, &["icon", "logo"]);
+    let llabel = qs.get("label").map(|v| v.to_string());
+    let lcolor =
+      qs_color(qs, &["lcolor", "labelColor"], icon.as_deref()).unwrap_or(d.label_color.clone());
+
+    let rlabel = qs.get("value").unwrap_or(&"unknown".to_string()).to_string();
+    let rcolor = qs_color(qs, &["rcolor", "color"], icon.as_deref()).unwrap_or(Color::DefaultValue);
+
+    let segments = qs.get("segments").map(|x| Segment::parse_list(x)).unwrap_or_default();
+
+    let style = qs.get("style").and_then(|x| BadgeStyle::parse(x)).unwrap_or(d.style.clone());
+
+    let icon_color = qs_first(qs, &["iconColor", "logoColor"])
+      .and_then(|x| Color::from_str(&x).ok())
+      .unwrap_or(d.icon_color(&style));
+
+    let radius = qs.get("radius").and_then(|v| v.parse::<u8>().ok());
+    let radius = radius.unwrap_or(style.default_radius()).min(d.max_radius);
+
+    let scale = qs.get("scale").and_then(|x| x.parse::<f32>().ok()).unwrap_or(1.0);
+    let scale = scale.clamp(0.1, d.max_scale);
+    let cache = qs_first(qs, &["cache", "cacheSeconds", "maxAge"])
+      .and_then(|x| x.parse::<u32>().ok())
+      .unwrap_or(d.cache)
+      .clamp(300, d.cache * 7);
+
+    let format = qs.get("format").map(|x| BadgeFormat::parse(x)).unwrap_or(BadgeFormat::Svg);
+
+    Ok(Badge {
+      llabel,
+      lcolor,
+      rlabel,
+      rcolor,
+      segments,
+      icon,
+      icon_color,
+      style,
+      radius,
+      scale,
+      cache,
+      format,
+      stale: None,
+      error: None,
+    })
+  }
+
+  pub fn from_qs_with(qs: &Dict, label: &str, value: &str, value_color: Color) -> Res<Badge> {
+    let mut badge = Badge::from_qs(qs)?;
+    badge.llabel = if badge.llabel.is_none() { Some(label.to_string()) } else { badge.llabel };
+    badge.rlabel = value.to_string();
+    badge.rcolor = match badge.rcolor {
+      Color::DefaultValue => value_color,
+      _ => badge.rcolor,
+    };
+    Ok(badge)
+  }
+
+  pub fn for_version(qs: &Dict, label: &str, value: &str) -> Res<Badge> {
+    let mut badge = Badge::from_qs(qs)?;
+
+    let value = match value.trim() {
+      "unknown" | "" => "unknown",
+      x if x.starts_with("v") => x,
+      x => &format!("v{}", x),
+    };
+
+    badge.llabel = if badge.llabel.is_none() { Some(label.to_string()) } else { badge.llabel };
+    badge.rlabel = value.to_string();
+    badge.rcolor = match badge.rcolor {
+      Color::DefaultValue => Color::from_version(value),
+      _ => badge.rcolor,
+    };
+
+    Ok(badge)
+  }
+
+  pub fn for_min_ver(qs: &Dict, label: &str, value: &str) -> Res<Badge> {
+    let mut badge = Badge::from_qs(qs)?;
+
+    badge.llabel = if badge.llabel.is_none() { Some(label.to_string()) } else { badge.llabel };
+    badge.rlabel = to_min_ver(value);
+    badge.rcolor = match badge.rcolor {
+      Color::DefaultValue => Color::Blue,
+      _ => badge.rcolor,
+    };
+
+    Ok(badge)
+  }
+
+  pub fn for_license(qs: &Dict, license: &str) -> Res<Badge> {
+    let mut badge = Badge::from_qs(qs)?;
+
+    badge.llabel = if badge.llabel.is_none() { Some("license".to_string()) } else { badge.llabel };
+    badge.rlabel = license.to_string();
+    badge.rcolor = match badge.rcolor {
+      Color::DefaultValue => Color::Blue,
+      _ => badge.rcolor,
+    };
+
+    Ok(badge)
+  }
+
+  pub fn for_dl(qs: &Dict, period: DlPeriod, count: u64) -> Res<Badge> {
+    let mut badge = Badge::from_qs(qs)?;
+
+    let value = millify(count);
+    let value = match period {
+      DlPeriod::Weekly => format!("{}/week", value),
+      DlPeriod::Monthly => format!("{}/month", value),
+      DlPeriod::Yearly => format!("{}/year", value),
+      DlPeriod::Total => value,
+    };
+
+    badge.llabel =
+      if badge.llabel.is_none() { Some("downloads".to_string()) } else { badge.llabel };
+    badge.rlabel = value;
+    badge.rcolor = value_color(qs, badge.rcolor, count as f64, Color::Green);
+
+    Ok(badge)
+  }
+
+  pub fn for_count(qs: &Dict, label: &str, value: u64) -> Res<Badge> {
+    let mut badge = Badge::from_qs(qs)?;
+
+    badge.llabel = if badge.llabel.is_none() { Some(label.to_string()) } else { badge.llabel };
+    badge.rlabel = millify(value);
+    badge.rcolor = value_color(qs, badge.rcolor, value as f64, Color::Blue);
+
+    Ok(badge)
+  }
+
+  pub fn for_size(qs: &Dict, label: &str, bytes: u64) -> Res<Badge> {
+    let mut badge = Badge::from_qs(qs)?;
+
+    badge.llabel = if badge.llabel.is_none() { Some(label.to_string()) } else { badge.llabel };
+    badge.rlabel = millify_iec(bytes);
+    badge.rcolor = value_color(qs, badge.rcolor, bytes as f64, Color::Blue);
+
+    Ok(badge)
+  }
+
+  pub fn for_percent(qs: &Dict, label: &str, value: f64) -> Res<Badge> {
+    let mut badge = Badge::from_qs(qs)?;
+
+    let default = Thresholds::coverage().color(value);
+    badge.llabel = if badge.llabel.is_none() { Some(label.to_string()) } else { badge.llabel };
+    badge.rlabel = format!("{value:.0}%");
+    badge.rcolor = value_color(qs, badge.rcolor, value, default);
+
+    Ok(badge)
+  }
+
+  // Score on 0-5 scale
+  pub fn for_rating(qs: &Dict, label: &str, score: f64) -> Res<Badge> {
+    let mut badge = Badge::from_qs(qs)?;
+
+    let default = Thresholds::rating().color(score);
+    badge.llabel = if badge.llabel.is_none() { Some(label.to_string()) } else { badge.llabel };
+    badge.rlabel = format!("{score:.1}/5");
+    badge.rcolor = value_color(qs, badge.rcolor, score, default);
+
+    Ok(badge)
+  }
+
+  // Same 0-5 score as `for_rating`, rendered as `★★★★½`
+  pub fn for_stars(qs: &Dict, label: &str, score: f64) -> Res<Badge> {
+    let mut badge = Badge::for_rating(qs, label, score)?;
+    badge.rlabel = render_stars(score, 5.0);
+    Ok(badge)
+  }
+
+  pub fn for_date(qs: &Dict, label: &str, date: DateTime<Utc>) -> Res<Badge> {
+    let mut badge = Badge::from_qs(qs)?;
+
+    let (value, default) = for_date(date);
+    let days = (Utc::now() - date).num_days() as f64;
+    badge.llabel = if badge.llabel.is_none() { Some(label.to_string()) } else { badge.llabel };
+    badge.rlabel = value;
+    badge.rcolor = value_color(qs, badge.rcolor, days, default);
+
+    Ok(badge)
+  }
+
+  pub fn to_str(&self) -> String {
+    if self.style == BadgeStyle::Social {
+      return self.to_social_str();
+    }
+
+    let icon = self.icon.as_deref().unwrap_or_default();
+    let icon = get_icon(icon, &self.icon_color.to_css());
+    let sm = self.style.metrics();
+
+    let ltext = self.llabel.clone().map(|s| s.trim().to_string()).unwrap_or_default();
+    let rtext = self.rlabel.clone().trim().to_string();
+    let (ltext, rtext) =
+      if sm.upper { (ltext.to_uppercase(), rtext.to_uppercase()) } else { (ltext, rtext) };
+    let (has_text, has_icon) = (!ltext.is_empty(), icon.is_some());
+
+    #[allow(clippy::nonminimal_bool)]
+    let mono = (!has_text && !has_icon)
+      || (has_icon && !has_text && self.lcolor == Color::DefaultLabel)
+      || (ltext.is_empty() && rtext.is_empty());
+
+    let fz = 110.0;
+    let ltw = sm.text_width(&ltext, fz);
+    let rtw = sm.text_width(&rtext, fz);
+    let pad = fz * sm.pad; // left / right padding
+    let gap = fz * sm.gap; // gap between left and right text
+
+    let iw = if icon.is_some() { fz * 1.2 } else { 0.0 };
+    #[allow(unused_assignments)]
+    let (mut lx, mut lw, mut rx, mut rw) = (0.0, 0.0, 0.0, 0.0);
+
+    if mono {
+      rx = if has_icon { pad + iw + gap } else { pad };
+      rw = if rtext.is_empty() { rx - gap + pad } else { rx + rtw + gap };
+    } else {
+      lx = if has_icon { pad + iw + gap } else { pad };
+      lw = if has_text { lx + ltw + gap } else { lx };
+      rx = lw + gap;
+      rw = rx + rtw + pad - lw;
+    }
+
+    // extra segments: (x, width, text width, text, color)
+    let mut segs = vec![];
+    let mut sx = lw + rw;
+    for seg in self.segments.iter() {
+      let text = seg.text.trim();
+      let text = if sm.upper { text.to_uppercase() } else { text.to_string() };
+      if text.is_empty() {
+        continue;
+      }
+
+      let tw = sm.text_width(&text, fz);
+      let sw = gap + tw + pad;
+      segs.push((sx, sw, tw, text, &seg.color));
+      sx += sw;
+    }
+
+    let (w, h) = (sx, fz * 1.75 * sm.height / 20.0);
+    let y = h * 0.56;
+
+    let title = if has_text { format!("{ltext}: {rtext}") } else { rtext.to_string() };
+    let title = segs.iter().fold(title, |acc, (_, _, _, text, _)| format!("{acc} {text}"));
+    let radius = (fz / 12.0) * self.radius as f32;
+    let (outx, outy) = (fz * 0.1 / 2.0, fz * 0.1);
+
+    let hh = sm.height * self.scale;
+    let ww = w * hh / h;
+
+    let font_weight = sm.bold.then_some("bold");
+    let letter_spacing = (sm.spacing > 0.0).then_some(sm.spacing * fz);
+
+    let svg = html!(svg xmlns="http://www.w3.org/2000/svg"
+      viewBox=(format!("0 0 {} {}", w, h))
+      width=(ww) height=(hh)
+      role="img" aria-label=(title)
+    {
+      title { (title) }
+
+      // background gradient
+      @if self.style == BadgeStyle::Flat {
+        linearGradient id="s" x2="0" y2="100%" {
+          stop offset="0" stop-opacity=".1" stop-color="#eee" {}
+          stop offset="1" stop-opacity=".1" {}
+        }
+      }
+
+      @if self.style == BadgeStyle::Plastic {
+        linearGradient id="s" x2="0" y2="100%" {
+          stop offset="0" stop-opacity=".7" stop-color="#fff" {}
+          stop offset=".1" stop-opacity=".1" stop-color="#aaa" {}
+          stop offset=".9" stop-opacity=".3" {}
+          stop offset="1" stop-opacity=".5" {}
+        }
+      }
+
+      // border-radius
+      mask id="r" { rect width=(w) height=(h) rx=(radius) fill="#fff" {} }
+
+      g mask="url(#r)" {
+        @if has_text || has_icon { rect x="0" y="0" width=(w) height=(h) fill=(self.lcolor.to_css()) {} }
+        rect x=(lw) y="0" width=(rw) height=(h) fill=(self.rcolor.to_css()) rx=(0) {}
+        @for (x, sw, _, _, color) in &segs {
+          rect x=(x) y="0" width=(sw) height=(h) fill=(color.to_css()) {}
+        }
+        @if self.style == BadgeStyle::Flat || self.style == BadgeStyle::Plastic {
+          rect x="0" y="0" width=(w) height=(h) fill="url(#s)" {}
+        }
+      }
+
+      @if icon.is_some() {
+        image x=(pad) y=((h-iw)/2.0) width=(iw) height=(iw) href=(icon.unwrap()) {}
+      }
+
+      g fill="#fff" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size=(fz)
+        font-weight=[font_weight] letter-spacing=[letter_spacing]
+        text-anchor="start" dominant-baseline="middle" text-rendering="geometricPrecision"
+      {
+        @if has_text {
+          @if sm.shadow {
+            text textLength=(ltw) x=(lx+outx) y=(y+outy) fill="#000" opacity="0.25" { (&ltext) }
+          }
+          text textLength=(ltw) x=(lx) y=(y) { (&ltext) }
+        }
+        @if sm.shadow {
+          text textLength=(rtw) x=(rx+outx) y=(y+outy) fill="#000" opacity="0.25" { (&rtext) }
+        }
+        text textLength=(rtw) x=(rx) y=(y) { (&rtext) }
+        @for (x, _, tw, text, _) in &segs {
+          @if sm.shadow {
+            text textLength=(tw) x=(x+gap+outx) y=(y+outy) fill="#000" opacity="0.25" { (text) }
+          }
+          text textLength=(tw) x=(x+gap) y=(y) { (text) }
+        }
+      }
+    });
+
+    svg.into_string()
+  }
+
+  // Split button with a count bubble, like GitHub's "Star" button (extra segments are ignored)
+  // https://github.com/badges/shields/blob/master/badge-maker/lib/badge-renderers.js
+  fn to_social_str(&self) -> String {
+    let icon = self.icon.as_deref().unwrap_or_default();
+    let icon = get_icon(icon, &self.icon_color.to_css());
+    let sm = self.style.metrics();
+
+    // shields.io capitalizes label in social style
+    let ltext = self.llabel.clone().map(|s| s.trim().to_string()).unwrap_or_default();
+    let mut chars = ltext.chars();
+    let ltext = match chars.next() {
+      Some(c) => c.to_uppercase().chain(chars).collect(),
+      None => ltext,
+    };
+
+    let rtext = self.rlabel.clone().trim().to_string();
+    let (has_text, has_icon, has_count) = (!ltext.is_empty(), icon.is_some(), !rtext.is_empty());
+
+    let fz = 110.0;
+    let h = fz * 1.75 * sm.height / 20.0;
+    let px = h / sm.height; // one pixel in viewBox units
+    let y = h * 0.56;
+
+    let ltw = sm.text_width(&ltext, fz);
+    let rtw = sm.text_width(&rtext, fz);
+    let pad = px * 5.0;
+    let iw = if has_icon { fz * 1.2 } else { 0.0 };
+
+    let lx = pad + if has_icon { iw + if has_text { px * 3.0 } else { 0.0 } } else { 0.0 };
+    let lw = lx + ltw + pad - px; // label box, drawn with 0.5px offset for crisp border
+
+    let bx = lw + px * 6.5; // bubble starts after gap with arrow
+    let bw = if has_count { rtw + pad * 2.0 } else { 0.0 };
+    let rx = bx + pad;
+
+    let w = if has_count { bx + bw + px * 0.5 } else { lw + px };
+    let (ay, ah) = (h * 0.35, h * 0.3); // arrow y and height
+
+    let title = if has_text { format!("{ltext}: {rtext}") } else { rtext.to_string() };
+    let radius = (fz / 12.0) * self.radius as f32;
+    let (outy, half) = (px, px * 0.5);
+
+    let hh = sm.height * self.scale;
+    let ww = w * hh / h;
+
+    let svg = html!(svg xmlns="http://www.w3.org/2000/svg"
+      viewBox=(format!("0 0 {} {}", w, h))
+      width=(ww) height=(hh)
+      role="img" aria-label=(title)
+    {
+      title { (title) }
+
+      linearGradient id="s" x2="0" y2="100%" {
+        stop offset="0" stop-opacity="0" stop-color="#fcfcfc" {}
+        stop offset="1" stop-opacity=".1" {}
+      }
+
+      g stroke="#d5d5d5" stroke-width=(px) {
+        rect x=(half) y=(half) width=(lw) height=(h-px) rx=(radius) fill="#fcfcfc" stroke="none" {}
+        rect x=(half) y=(half) width=(lw) height=(h-px) rx=(radius) fill="url(#s)" {}
+
+        @if has_count {
+          rect x=(bx) y=(half) width=(bw) height=(h-px) rx=(radius) fill="#fafafa" {}
+          path d=(format!("M{} {}l{} {}l{} {}", bx, ay, -px * 4.0, ah / 2.0, px * 4.0, ah / 2.0))
+            fill="#fafafa" {}
+          path d=(format!("M{} {}v{}", bx, ay, ah)) stroke="#fafafa" {}
+        }
+      }
+
+      @if icon.is_some() {
+        image x=(pad) y=((h-iw)/2.0) width=(iw) height=(iw) href=(icon.unwrap()) {}
+      }
+
+      g fill="#333" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size=(fz)
+        font-weight="bold" text-anchor="start" dominant-baseline="middle"
+        text-rendering="geometricPrecision"
+      {
+        @if has_text {
+          text textLength=(ltw) x=(lx) y=(y+outy) fill="#fff" { (&ltext) }
+          text textLength=(ltw) x=(lx) y=(y) { (&ltext) }
+        }
+        @if has_count {
+          text textLength=(rtw) x=(rx) y=(y+outy) fill="#fff" { (&rtext) }
+          text textLength=(rtw) x=(rx) y=(y) { (&rtext) }
+        }
+      }
+    });
+
+    svg.into_string()
+  }
+}
+
+impl Badge {
+  // Content in `self.format`, rasterization can fail for png and webp
+  pub fn to_bytes(&self) -> Res<Vec<u8>> {
+    match self.format {
+      BadgeFormat::Json => Ok(serde_json::to_vec(self)?),
+      BadgeFormat::Png => to_png(&self.to_str()),
+      BadgeFormat::Webp => to_webp(&self.to_str()),
+      BadgeFormat::Svg => Ok(self.to_str().into_bytes()),
+    }
+  }
+}
@@ -0,0 +1,126 @@
+use super::badge::{BadgeFormat, Res};
+use super::{Badge, BadgeStyle, Color, Defaults, Segment};
+
+// Framework-free way to build badge, unset options fall back to `Defaults`:
+// `Badge::builder().label("build").value("passing").color(Color::Green).render_svg()`
+#[derive(Debug, Clone, Default)]
+pub struct BadgeBuilder {
+  label: Option<String>,
+  value: String,
+  color: Color,
+  label_color: Option<Color>,
+  segments: Vec<Segment>,
+  icon: Option<String>,
+  icon_color: Option<Color>,
+  style: Option<BadgeStyle>,
+  radius: Option<u8>,
+  scale: Option<f32>,
+  cache: Option<u32>,
+}
+
+impl Badge {
+  pub fn builder() -> BadgeBuilder {
+    BadgeBuilder::default()
+  }
+}
+
+impl BadgeBuilder {
+  pub fn label(mut self, label: &str) -> Self {
+    self.label = Some(label.to_string());
+    self
+  }
+
+  pub fn value(mut self, value: &str) -> Self {
+    self.value = value.to_string();
+    self
+  }
+
+  pub fn color(mut self, color: Color) -> Self {
+    self.color = color;
+    self
+  }
+
+  pub fn label_color(mut self, color: Color) -> Self {
+    self.label_color = Some(color);
+    self
+  }
+
+  pub fn segment(mut self, text: &str, color: Color) -> Self {
+    self.segments.push(Segment { text: text.to_string(), color });
+    self
+  }
+
+  // Icon name from simple-icons or custom icons, e.g. `rust`, or `data:image/...` uri
+  pub fn icon(mut self, icon: &str) -> Self {
+    self.icon = Some(icon.to_string());
+    self
+  }
+
+  pub fn icon_color(mut self, color: Color) -> Self {
+    self.icon_color = Some(color);
+    self
+  }
+
+  pub fn style(mut self, style: BadgeStyle) -> Self {
+    self.style = Some(style);
+    self
+  }
+
+  pub fn radius(mut self, radius: u8) -> Self {
+    self.radius = Some(radius);
+    self
+  }
+
+  pub fn scale(mut self, scale: f32) -> Self {
+    self.scale = Some(scale);
+    self
+  }
+
+  pub fn cache(mut self, seconds: u32) -> Self {
+    self.cache = Some(seconds);
+    self
+  }
+
+  pub fn build(self) -> Badge {
+    let d = Defaults::get();
+    let style = self.style.unwrap_or(d.style.clone());
+    let radius = self.radius.unwrap_or(style.default_radius()).min(d.max_radius);
+
+    Badge {
+      llabel: self.label,
+      lcolor: self.label_color.unwrap_or(d.label_color.clone()),
+      rlabel: self.value,
+      rcolor: self.color,
+      segments: self.segments,
+      icon: self.icon,
+      icon_color: self.icon_color.unwrap_or(d.icon_color(&style)),
+      radius,
+      scale: self.scale.unwrap_or(1.0).clamp(0.1, d.max_scale),
+      cache: self.cache.unwrap_or(d.cache),
+      style,
+      format: BadgeFormat::Svg,
+      stale: None,
+      error: None,
+    }
+  }
+
+  pub fn render_svg(self) -> String {
+    self.build().to_str()
+  }
+
+  pub fn render_json(self) -> Res<String> {
+    Ok(serde_json::to_string(&self.build())?)
+  }
+
+  pub fn render_png(self) -> Res<Vec<u8>> {
+    self.format(BadgeFormat::Png).to_bytes()
+  }
+
+  pub fn render_webp(self) -> Res<Vec<u8>> {
+    self.format(BadgeFormat::Webp).to_bytes()
+  }
+
+  fn format(self, format: BadgeFormat) -> Badge {
+    Badge { format, ..self.build() }
+  }
+}
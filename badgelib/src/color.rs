@@ -80,6 +80,7 @@ impl Color {
   }
 
   // "impl FromStr for Color" are stupid becase require to import "std::str::FromStr" everywhere
+  #[allow(clippy::should_implement_trait)]
   pub fn from_str(s: &str) -> Result<Self, &'static str> {
     let s = s.to_lowercase().trim().replace("#", "");
     match s.as_ref() {
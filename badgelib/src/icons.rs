@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use anyhow::anyhow;
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use resvg::usvg;
+
+use super::_icons::{ALIASES, ICONS};
+use super::Color;
+use super::badge::Res;
+use super::raster::FONTDB;
+
+// Decoded size of custom icon, simple-icons are below 10 KB
+pub const MAX_ICON_SIZE: usize = 64 * 1024;
+
+// Icon from simple-icons, generated into `_icons.rs` by build.rs
+pub(crate) struct SimpleIcon {
+  pub path: &'static str,
+  pub hex: &'static str,
+  pub title: &'static str,
+}
+
+static CUSTOM: OnceLock<HashMap<String, String>> = OnceLock::new();
+static REMOTE_HOOK: OnceLock<fn(&str) -> Option<String>> = OnceLock::new();
+
+// Extra icons by name (values are from `sanitize_icon`), checked before simple-icons
+pub fn set_custom_icons(icons: HashMap<String, String>) {
+  let icons = icons.into_iter().map(|(k, v)| (k.to_lowercase(), v)).collect();
+  let _ = CUSTOM.set(icons);
+}
+
+// Rendering is sync, so icons by `https://` url are fetched by server before and only looked up
+pub fn set_remote_icon_hook(hook: fn(&str) -> Option<String>) {
+  let _ = REMOTE_HOOK.set(hook);
+}
+
+// MARK: Sanitize
+
+fn raster_mime(data: &[u8]) -> Option<&'static str> {
+  match data {
+    [0x89, b'P', b'N', b'G', ..] => Some("image/png"),
+    [0xff, 0xd8, 0xff, ..] => Some("image/jpeg"),
+    [b'G', b'I', b'F', b'8', ..] => Some("image/gif"),
+    [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Some("image/webp"),
+    _ => None,
+  }
+}
+
+// SVG is parsed and written back by usvg, so scripts, event handlers, links, foreign objects
+// and external references are dropped, only shapes and embedded images are left
+fn sanitize_svg(data: &[u8]) -> Res<String> {
+  let mut opt = usvg::Options { fontdb: FONTDB.clone(), ..Default::default() };
+  opt.image_href_resolver.resolve_string = Box::new(|_, _| None);
+
+  let tree = usvg::Tree::from_data(data, &opt).map_err(|e| anyhow!("invalid svg icon: {e}"))?;
+  if !tree.root().has_children() {
+    return Err(anyhow!("empty svg icon"));
+  }
+
+  Ok(tree.to_string(&usvg::WriteOptions::default()))
+}
+
+// Makes data URI from SVG or PNG / JPEG / GIF / WebP, mime is detected from content
+pub fn sanitize_icon(data: &[u8]) -> Res<String> {
+  if data.len() > MAX_ICON_SIZE {
+    return Err(anyhow!("icon is too large: {} bytes, max {}", data.len(), MAX_ICON_SIZE));
+  }
+
+  let (mime, data) = match raster_mime(data) {
+    Some(mime) => (mime, data.to_vec()),
+    None => ("image/svg+xml", sanitize_svg(data)?.into_bytes()),
+  };
+
+  Ok(format!("data:{};base64,{}", mime, BASE64_STANDARD.encode(data)))
+}
+
+// `data:image/svg+xml;base64,...` from query, `+` can come as space after form decoding
+fn from_data_uri(uri: &str) -> Res<String> {
+  let uri = uri.replace(' ', "+");
+  let (meta, payload) = uri
+    .strip_prefix("data:")
+    .and_then(|x| x.split_once(','))
+    .ok_or_else(|| anyhow!("invalid data uri"))?;
+
+  if payload.len() > MAX_ICON_SIZE * 4 / 3 + 4 {
+    return Err(anyhow!("icon is too large"));
+  }
+
+  let data = match meta.ends_with(";base64") {
+    true => BASE64_STANDARD.decode(payload)?,
+    false => payload.as_bytes().to_vec(),
+  };
+
+  sanitize_icon(&data)
+}
+
+// MARK: Lookup
+
+// By slug (`googlechrome`), title (`Google Chrome`) or alias from simple-icons data
+fn find_simple_icon(name: &str) -> Option<&'static SimpleIcon> {
+  let pretenders = [
+    name.to_lowercase(),
+    name.to_lowercase().replace('-', "").replace("!", "").replace("_", "").replace(" ", ""),
+    name.to_lowercase().replace('.', "dot").replace("+", "plus"),
+  ];
+
+  let slug = pretenders[2].replace('&', "and").replace(|c: char| !c.is_ascii_alphanumeric(), "");
+  pretenders.iter().find_map(|n| ICONS.get(n)).or_else(|| ICONS.get(ALIASES.get(&slug)?))
+}
+
+// Brand color of simple-icons icon, used for `color=brand`
+pub fn brand_color(name: &str) -> Option<Color> {
+  let icon = find_simple_icon(name)?;
+  Color::from_str(icon.hex).ok()
+}
+
+pub(crate) fn get_icon(name: &str, color: &str) -> Option<String> {
+  if name.starts_with("data:") {
+    return from_data_uri(name).ok();
+  }
+
+  if name.starts_with("https://") {
+    return REMOTE_HOOK.get().and_then(|f| f(name));
+  }
+
+  if let Some(icon) = CUSTOM.get().and_then(|x| x.get(&name.to_lowercase())) {
+    return Some(icon.clone());
+  }
+
+  let icon = find_simple_icon(name)?.path;
+  let icon = format!(
+    r#"<svg xmlns="http://www.w3.org/2000/svg" role="img" viewBox="0 0 24 24" fill="{}"><path d="{}" /></svg>"#,
+    color, icon
+  );
+
+  Some(format!("data:image/svg+xml;base64,{}", BASE64_STANDARD.encode(icon)))
+}
@@ -0,0 +1,23 @@
+#![forbid(absolute_paths_not_starting_with_crate)]
+
+mod _icons;
+mod _width;
+mod badge;
+mod builder;
+mod color;
+mod icons;
+mod raster;
+#[cfg(feature = "axum")]
+mod response;
+mod thresholds;
+pub mod utils;
+
+pub use badge::{Badge, BadgeFormat, BadgeStyle, Defaults, Dict, DlPeriod, Res, Segment};
+pub use builder::BadgeBuilder;
+pub use color::Color;
+pub use icons::{
+  MAX_ICON_SIZE, brand_color, sanitize_icon, set_custom_icons, set_remote_icon_hook,
+};
+#[cfg(feature = "axum")]
+pub use response::set_stale_hook;
+pub use thresholds::Thresholds;
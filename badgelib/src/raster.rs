@@ -0,0 +1,52 @@
+use std::sync::{Arc, LazyLock};
+
+use anyhow::anyhow;
+use image::ImageEncoder;
+use image::codecs::webp::WebPEncoder;
+use resvg::{tiny_skia, usvg};
+
+use super::badge::Res;
+
+// Fonts are bundled, so raster output does not depend on fonts installed in the system
+pub(crate) static FONTDB: LazyLock<Arc<usvg::fontdb::Database>> = LazyLock::new(|| {
+  let mut db = usvg::fontdb::Database::new();
+  db.load_font_data(include_bytes!("fonts/DejaVuSans.ttf").to_vec());
+  db.load_font_data(include_bytes!("fonts/DejaVuSans-Bold.ttf").to_vec());
+  db.set_sans_serif_family("DejaVu Sans");
+  Arc::new(db)
+});
+
+fn rasterize(svg: &str) -> Res<tiny_skia::Pixmap> {
+  let opt = usvg::Options { fontdb: FONTDB.clone(), ..Default::default() };
+  let tree = usvg::Tree::from_str(svg, &opt)?;
+
+  let size = tree.size().to_int_size();
+  let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
+    .ok_or_else(|| anyhow!("invalid image size: {}x{}", size.width(), size.height()))?;
+
+  resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+  Ok(pixmap)
+}
+
+pub fn to_png(svg: &str) -> Res<Vec<u8>> {
+  Ok(rasterize(svg)?.encode_png()?)
+}
+
+pub fn to_webp(svg: &str) -> Res<Vec<u8>> {
+  let pixmap = rasterize(svg)?;
+
+  // tiny-skia stores premultiplied colors, encoders expect straight alpha
+  let data = pixmap
+    .pixels()
+    .iter()
+    .flat_map(|x| {
+      let c = x.demultiply();
+      [c.red(), c.green(), c.blue(), c.alpha()]
+    })
+    .collect::<Vec<u8>>();
+
+  let mut buf = Vec::new();
+  let (w, h) = (pixmap.width(), pixmap.height());
+  WebPEncoder::new_lossless(&mut buf).write_image(&data, w, h, image::ExtendedColorType::Rgba8)?;
+  Ok(buf)
+}
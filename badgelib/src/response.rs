@@ -0,0 +1,49 @@
+use std::sync::OnceLock;
+
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+
+use super::Badge;
+
+static STALE_HOOK: OnceLock<fn() -> Option<DateTime<Utc>>> = OnceLock::new();
+
+// Server can report that badge was built from stale upstream data without passing it to builders
+pub fn set_stale_hook(hook: fn() -> Option<DateTime<Utc>>) {
+  let _ = STALE_HOOK.set(hook);
+}
+
+impl IntoResponse for Badge {
+  fn into_response(mut self) -> Response {
+    // stale value served because upstream failed, so ask clients to come back soon
+    self.stale = self.stale.or_else(|| STALE_HOOK.get().and_then(|f| f()));
+    if self.stale.is_some() {
+      self.cache = self.cache.min(300);
+    }
+
+    let cc = format!("public,max-age={0},s-maxage=300,stale-while-revalidate={0}", self.cache);
+    let stale = self.stale.map(|x| x.to_rfc3339());
+    let mut rep = match self.to_bytes() {
+      Ok(content) => {
+        // content hash, so same badge has same tag on every instance
+        let etag = format!("\"{:016x}\"", xxhash_rust::xxh3::xxh3_64(&content));
+        let headers = [
+          (header::CONTENT_TYPE, self.format.mime()),
+          (header::CACHE_CONTROL, &cc),
+          (header::ETAG, &etag),
+        ];
+        (StatusCode::OK, headers, content).into_response()
+      }
+      Err(e) => {
+        tracing::error!("failed to rasterize badge: {:?}", e);
+        (StatusCode::INTERNAL_SERVER_ERROR, "failed to render badge").into_response()
+      }
+    };
+
+    if let Some(stale) = stale.and_then(|x| x.parse().ok()) {
+      rep.headers_mut().insert("x-badge-stale", stale);
+    }
+
+    rep
+  }
+}
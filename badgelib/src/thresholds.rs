@@ -0,0 +1,79 @@
+use super::Color;
+use super::badge::Dict;
+
+// Ascending list of steps, value gets color of the highest step it reaches:
+// `50:red,75:yellow,90:green` -> 80 is yellow, 95 is green (values below first step use its color)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Thresholds(Vec<(f64, Color)>);
+
+impl Thresholds {
+  pub fn new(steps: &[(f64, Color)]) -> Self {
+    let mut steps = steps.to_vec();
+    steps.sort_by(|a, b| a.0.total_cmp(&b.0));
+    Thresholds(steps)
+  }
+
+  // Percentage of something good (coverage, pass rate)
+  pub fn coverage() -> Self {
+    Self::new(&[
+      (0.0, Color::Red),
+      (50.0, Color::Orange),
+      (75.0, Color::Yellow),
+      (90.0, Color::Green),
+    ])
+  }
+
+  pub fn downloads() -> Self {
+    Self::new(&[
+      (0.0, Color::Red),
+      (100.0, Color::Orange),
+      (1_000.0, Color::Yellow),
+      (10_000.0, Color::Green),
+    ])
+  }
+
+  // Age in days, same colors as `last commit` badges
+  pub fn age() -> Self {
+    Self::new(&[
+      (0.0, Color::Green),
+      (2.0, Color::Yellow),
+      (30.0, Color::Orange),
+      (366.0, Color::Grey),
+    ])
+  }
+
+  // Rating on 0-5 scale
+  pub fn rating() -> Self {
+    Self::new(&[(0.0, Color::Red), (2.0, Color::Orange), (3.0, Color::Yellow), (4.0, Color::Green)])
+  }
+
+  // Named scheme or list of `value:color` pairs, invalid value ignored as other options
+  pub fn parse(s: &str) -> Option<Self> {
+    match s.trim().to_lowercase().as_str() {
+      "coverage" => return Some(Self::coverage()),
+      "downloads" => return Some(Self::downloads()),
+      "age" => return Some(Self::age()),
+      "rating" => return Some(Self::rating()),
+      _ => {}
+    }
+
+    let steps = s
+      .split(',')
+      .map(|x| {
+        let (value, color) = x.split_once(':')?;
+        Some((value.trim().parse::<f64>().ok()?, Color::from_str(color).ok()?))
+      })
+      .collect::<Option<Vec<_>>>()?;
+
+    if steps.is_empty() { None } else { Some(Self::new(&steps)) }
+  }
+
+  pub fn from_qs(qs: &Dict) -> Option<Self> {
+    qs.get("thresholds").and_then(|x| Self::parse(x))
+  }
+
+  pub fn color(&self, value: f64) -> Color {
+    let step = self.0.iter().rev().find(|x| value >= x.0).or(self.0.first());
+    step.map(|x| x.1.clone()).unwrap_or_default()
+  }
+}
@@ -1,6 +1,6 @@
 use chrono::{DateTime, Utc};
 
-use super::{_width::WIDTHS, Color};
+use super::{_width::WIDTHS, Color, Thresholds};
 
 pub fn cacl_width(text: &str) -> f32 {
   let fallback_width = WIDTHS[64]; // Width as "@" for overflows
@@ -82,14 +82,15 @@ pub fn for_date(date: DateTime<Utc>) -> (String, Color) {
   let now = Utc::now();
   let duration = now.signed_duration_since(date);
 
-  let (label, color) = match duration.num_days() {
-    0 => ("today", Color::Green),
-    1 => ("yesterday", Color::Green),
-    2..=6 => ("this week", Color::Yellow),
-    7..=29 => ("this month", Color::Yellow),
-    30..=365 => ("this year", Color::Orange),
-    _ => ("long ago", Color::Grey),
+  let days = duration.num_days();
+  let label = match days {
+    0 => "today",
+    1 => "yesterday",
+    2..=6 => "this week",
+    7..=29 => "this month",
+    30..=365 => "this year",
+    _ => "long ago",
   };
 
-  (label.into(), color)
+  (label.into(), Thresholds::age().color(days as f64))
 }
@@ -1,16 +1,12 @@
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, DlPeriod};
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::Color;
-use crate::server::{Dict, Res};
-use crate::{
-  badgelib::{Badge, DlPeriod},
-  server::BadgeRep,
-};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Data {
   version: String,
   license: String,
@@ -19,21 +15,24 @@ struct Data {
   rating: f64,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(name: String) -> Res<Data> {
-  let url = format!("https://addons.mozilla.org/api/v4/addons/addon/{name}/");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
+  cached("amo:get_data", name, |name| async move {
+    let url = format!("https://addons.mozilla.org/api/v4/addons/addon/{name}/");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
 
-  let version = dat["current_version"]["version"].as_str().unwrap_or("unknown").to_string();
-  let license = dat["current_version"]["license"]["url"].as_str().unwrap_or("unknown").to_string();
-  let license = license.split('/').last().unwrap_or(&license).to_string();
-  let license = license.strip_suffix(".html").unwrap_or(&license).to_string();
-  let dlw = dat["weekly_downloads"].as_u64().unwrap_or(0);
-  let users = dat["average_daily_users"].as_u64().unwrap_or(0);
-  let rating = dat["ratings"]["average"].as_f64().unwrap_or(0.0);
+    let version = dat["current_version"]["version"].as_str().unwrap_or("unknown").to_string();
+    let license =
+      dat["current_version"]["license"]["url"].as_str().unwrap_or("unknown").to_string();
+    let license = license.split('/').next_back().unwrap_or(&license).to_string();
+    let license = license.strip_suffix(".html").unwrap_or(&license).to_string();
+    let dlw = dat["weekly_downloads"].as_u64().unwrap_or(0);
+    let users = dat["average_daily_users"].as_u64().unwrap_or(0);
+    let rating = dat["ratings"]["average"].as_f64().unwrap_or(0.0);
 
-  Ok(Data { version, license, dlw, users, rating })
+    Ok(Data { version, license, dlw, users, rating })
+  })
+  .await
 }
 
 #[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display)]
@@ -56,7 +55,7 @@ pub async fn handler(Path((kind, name)): Path<(Kind, String)>, Query(qs): Query<
     Kind::Version => Ok(Badge::for_version(&qs, "mozilla add-on", &rs.version)?),
     Kind::License => Ok(Badge::for_license(&qs, &rs.license)?),
     Kind::Weekly => Ok(Badge::for_dl(&qs, DlPeriod::Weekly, rs.dlw)?),
-    Kind::Rating => Ok(Badge::new("rating", &format!("{:.1}/5", rs.rating), Color::DefaultValue)),
+    Kind::Rating => Ok(Badge::for_rating(&qs, "rating", rs.rating)?),
     Kind::Users => Ok(Badge::for_count(&qs, "users", rs.users)?),
   }
 }
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, OnceLock};
+use std::time::Duration;
+
+use axum::http::Extensions;
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware, Middleware, Next};
+
+use crate::server::{ErrorKind, Res};
+
+struct ClientConfig {
+  connect_timeout: Duration,
+  read_timeout: Duration,
+  retries: u32,          // extra attempts on 5xx or connection error
+  backoff: Duration,     // delay before first retry, doubled on each next one
+  proxy: Option<String>, // system proxy from `HTTPS_PROXY` is used if not set
+  ca_file: Option<String>,
+  base_url: Option<String>,
+  hosts: HashMap<String, String>,
+}
+
+static CONFIG: LazyLock<ClientConfig> = LazyLock::new(|| {
+  let c = &crate::config::get().upstream;
+  ClientConfig {
+    connect_timeout: Duration::from_millis(c.connect_timeout_ms),
+    read_timeout: Duration::from_millis(c.read_timeout_ms),
+    retries: c.retries,
+    backoff: Duration::from_millis(c.backoff_ms),
+    proxy: c.proxy.clone(),
+    ca_file: c.ca_file.clone(),
+    base_url: c.base_url.clone(),
+    hosts: c.hosts.clone(),
+  }
+});
+
+// MARK: Rewrite
+
+// Upstreams can be redirected to mirror or mock server, host mapping wins over `base_url`
+struct Rewrite;
+
+impl Rewrite {
+  fn rewrite(url: &reqwest::Url) -> Option<reqwest::Url> {
+    let host = url.host_str()?;
+    let path = match url.query() {
+      Some(qs) => format!("{}?{}", url.path(), qs),
+      None => url.path().to_string(),
+    };
+    let url = match (CONFIG.hosts.get(host), &CONFIG.base_url) {
+      (Some(base), _) => format!("{}{}", base.trim_end_matches('/'), path),
+      (None, Some(base)) => format!("{}/{}{}", base.trim_end_matches('/'), host, path),
+      (None, None) => return None,
+    };
+
+    reqwest::Url::parse(&url).ok()
+  }
+}
+
+#[async_trait::async_trait]
+impl Middleware for Rewrite {
+  async fn handle(
+    &self,
+    mut req: reqwest::Request,
+    extensions: &mut Extensions,
+    next: Next<'_>,
+  ) -> reqwest_middleware::Result<reqwest::Response> {
+    if let Some(url) = Self::rewrite(req.url()) {
+      *req.url_mut() = url;
+    }
+
+    next.run(req, extensions).await
+  }
+}
+
+// MARK: Retry
+
+struct Retry;
+
+#[async_trait::async_trait]
+impl Middleware for Retry {
+  async fn handle(
+    &self,
+    req: reqwest::Request,
+    extensions: &mut Extensions,
+    next: Next<'_>,
+  ) -> reqwest_middleware::Result<reqwest::Response> {
+    let mut attempt = 0;
+    loop {
+      // streaming bodies can't be cloned, so such requests are sent once
+      let Some(copy) = req.try_clone().filter(|_| attempt < CONFIG.retries) else {
+        return next.run(req, extensions).await;
+      };
+
+      let rep = next.clone().run(copy, extensions).await;
+      let retry = match &rep {
+        Ok(x) => x.status().is_server_error(),
+        Err(reqwest_middleware::Error::Reqwest(e)) => e.is_connect() || e.is_timeout(),
+        Err(_) => false,
+      };
+
+      if !retry {
+        return rep;
+      }
+
+      let delay = CONFIG.backoff * 2u32.pow(attempt);
+      tracing::warn!("retry #{} {} in {:?}", attempt + 1, req.url(), delay);
+      tokio::time::sleep(delay).await;
+      attempt += 1;
+    }
+  }
+}
+
+// MARK: Client
+
+fn build_client() -> Res<ClientWithMiddleware> {
+  let ua = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+  let mut client = reqwest::Client::builder()
+    .user_agent(ua)
+    .connect_timeout(CONFIG.connect_timeout)
+    .read_timeout(CONFIG.read_timeout)
+    .pool_idle_timeout(Duration::from_secs(90));
+
+  if let Some(proxy) = &CONFIG.proxy {
+    client = client.proxy(reqwest::Proxy::all(proxy)?);
+  }
+
+  if let Some(path) = &CONFIG.ca_file {
+    let pem = std::fs::read(path).map_err(|e| anyhow::anyhow!("failed to read {path}: {e}"))?;
+    for cert in reqwest::Certificate::from_pem_bundle(&pem)? {
+      client = client.add_root_certificate(cert);
+    }
+  }
+
+  // metrics are collected for every attempt
+  let client =
+    ClientBuilder::new(client.build()?).with(Rewrite).with(Retry).with(crate::metrics::Upstream);
+  Ok(client.build())
+}
+
+// Body is read by chunks, so response over `limit` is rejected without buffering it whole
+pub(crate) async fn read_limited(mut rep: reqwest::Response, limit: usize) -> Res<Vec<u8>> {
+  if let Some(len) = rep.content_length()
+    && len > limit as u64
+  {
+    return Err(ErrorKind::UpstreamParse.msg(format!("response too large: {len} bytes")));
+  }
+
+  let mut data = vec![];
+  while let Some(chunk) = rep.chunk().await? {
+    if data.len() + chunk.len() > limit {
+      return Err(ErrorKind::UpstreamParse.msg(format!("response too large: over {limit} bytes")));
+    }
+    data.extend_from_slice(&chunk);
+  }
+
+  Ok(data)
+}
+
+static CLIENT: OnceLock<ClientWithMiddleware> = OnceLock::new();
+
+// Called on startup to report invalid config early, otherwise client is created on first use
+pub(crate) fn init_client() -> Res {
+  let _ = CLIENT.set(build_client()?);
+  Ok(())
+}
+
+pub(crate) fn get_client() -> &'static ClientWithMiddleware {
+  CLIENT.get_or_init(|| build_client().expect("failed to create http client"))
+}
@@ -1,38 +1,40 @@
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, Color, DlPeriod};
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::{Badge, Color, DlPeriod};
+use crate::cache::cached;
 use crate::server::BadgeRep;
-use crate::server::{Dict, Res};
+use crate::server::{Dict, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Data {
   version: String,
   license: String,
   dlt: u64,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(name: String) -> Res<Data> {
-  // also: https://cljdoc.org/badge/wing/wing
-  let url = format!("https://clojars.org/api/artifacts/{name}");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
-
-  let version = dat["latest_version"].as_str().unwrap_or("unknown").into();
-  let license = dat["licenses"]
-    .as_array()
-    .and_then(|x| {
-      let items = x.iter().filter_map(|x| x["name"].as_str()).collect::<Vec<_>>();
-      if items.is_empty() { None } else { Some(items.join(" | ")) }
-    })
-    .unwrap_or("unknown".into());
-
-  let dlt = dat["downloads"].as_u64().unwrap_or(0);
-
-  Ok(Data { version, license, dlt })
+  cached("clojars:get_data", name, |name| async move {
+    // also: https://cljdoc.org/badge/wing/wing
+    let url = format!("https://clojars.org/api/artifacts/{name}");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+
+    let version = dat["latest_version"].as_str().unwrap_or("unknown").into();
+    let license = dat["licenses"]
+      .as_array()
+      .and_then(|x| {
+        let items = x.iter().filter_map(|x| x["name"].as_str()).collect::<Vec<_>>();
+        if items.is_empty() { None } else { Some(items.join(" | ")) }
+      })
+      .unwrap_or("unknown".into());
+
+    let dlt = dat["downloads"].as_u64().unwrap_or(0);
+
+    Ok(Data { version, license, dlt })
+  })
+  .await
 }
 
 #[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display, PartialEq)]
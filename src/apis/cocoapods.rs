@@ -1,13 +1,13 @@
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, Color};
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::{Badge, Color};
+use crate::cache::cached;
 use crate::server::BadgeRep;
-use crate::server::{Dict, Res};
+use crate::server::{Dict, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Data {
   version: String,
   license: String,
@@ -15,19 +15,21 @@ struct Data {
   runtime: String,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(name: String) -> Res<Data> {
-  // also: https://metrics.cocoapods.org/api/v1/pods/SwiftyJSON
-  let url = format!("https://trunk.cocoapods.org/api/v1/pods/{name}/specs/latest");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
+  cached("cocoapods:get_data", name, |name| async move {
+    // also: https://metrics.cocoapods.org/api/v1/pods/SwiftyJSON
+    let url = format!("https://trunk.cocoapods.org/api/v1/pods/{name}/specs/latest");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
 
-  let version = dat["version"].as_str().unwrap_or("unknown").to_string();
-  let license = dat["license"]["type"].as_str().unwrap_or("unknown").to_string();
-  let platforms = dat["platforms"].as_object().unwrap().keys().map(|x| x.to_string()).collect();
-  let runtime = dat["swift_version"].as_str().unwrap_or("unknown").to_string();
+    let version = dat["version"].as_str().unwrap_or("unknown").to_string();
+    let license = dat["license"]["type"].as_str().unwrap_or("unknown").to_string();
+    let platforms = dat["platforms"].as_object().unwrap().keys().map(|x| x.to_string()).collect();
+    let runtime = dat["swift_version"].as_str().unwrap_or("unknown").to_string();
 
-  Ok(Data { version, license, platforms, runtime })
+    Ok(Data { version, license, platforms, runtime })
+  })
+  .await
 }
 
 #[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display, PartialEq)]
@@ -1,13 +1,13 @@
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, Color};
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::{Badge, Color};
-use crate::server::BadgeRep;
-use crate::server::{Dict, Res};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, ErrorKind, Path, Res};
 
 #[derive(Debug, Deserialize, Serialize, strum::Display, Hash, Clone, PartialEq, Eq)]
+#[strum(serialize_all = "lowercase")] // used in url
 pub(crate) enum Service {
   #[serde(rename = "github", alias = "gh")]
   GitHub,
@@ -17,13 +17,15 @@ pub(crate) enum Service {
   Bitbucket,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_coverage(service: Service, name: String) -> Res<u64> {
-  let url = format!("https://codecov.io/{service}/{name}/graph/badge.txt");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.text().await?;
-  let cov = dat.trim().parse::<u64>()?;
-  Ok(cov)
+  cached("codecov:get_coverage", (service, name), |(service, name)| async move {
+    let url = format!("https://codecov.io/{service}/{name}/graph/badge.txt");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.text().await?;
+    let cov = dat.trim().parse::<u64>()?;
+    Ok(cov)
+  })
+  .await
 }
 
 #[derive(Deserialize)]
@@ -38,16 +40,17 @@ pub async fn handler(
   Path(Params { service, user, repo, branch }): Path<Params>,
   Query(qs): Query<Dict>,
 ) -> BadgeRep {
-  println!(">> service: {:?}, user: {}, repo: {}, branch: {:?}", service, user, repo, branch);
-
   let name = match branch {
     Some(branch) => format!("{user}/{repo}/branch/{branch}"),
     None => format!("{user}/{repo}"),
   };
 
-  // todo: coverage color
+  // codecov responds with `unknown` text when repo has no coverage yet
   match get_coverage(service, name).await {
-    Ok(cov) => Ok(Badge::from_qs_with(&qs, "coverage", &format!("{cov}%"), Color::DefaultValue)?),
-    Err(_) => Ok(Badge::from_qs_with(&qs, "coverage", "unknown", Color::Grey)?),
+    Ok(cov) => Ok(Badge::for_percent(&qs, "coverage", cov as f64)?),
+    Err(e) if ErrorKind::of(&e) == ErrorKind::UpstreamParse => {
+      Ok(Badge::from_qs_with(&qs, "coverage", "unknown", Color::Grey)?)
+    }
+    Err(e) => Err(e.into()),
   }
 }
@@ -1,27 +1,29 @@
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::Badge;
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::Badge;
-use crate::server::{BadgeRep, Dict, Res};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Data {
   version: String,
   license: String,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(name: String) -> Res<Data> {
-  let url = format!("https://fastapi.metacpan.org/v1/release/{name}");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
+  cached("cpan:get_data", name, |name| async move {
+    let url = format!("https://fastapi.metacpan.org/v1/release/{name}");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
 
-  let version = dat["version"].as_str().unwrap_or("unknown").to_string();
-  let license = dat["license"][0].as_str().unwrap_or("unknown").to_string();
+    let version = dat["version"].as_str().unwrap_or("unknown").to_string();
+    let license = dat["license"][0].as_str().unwrap_or("unknown").to_string();
 
-  Ok(Data { version, license })
+    Ok(Data { version, license })
+  })
+  .await
 }
 
 #[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display, PartialEq)]
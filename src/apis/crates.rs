@@ -1,12 +1,12 @@
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, Color, DlPeriod};
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::{Badge, Color, DlPeriod};
-use crate::server::{BadgeRep, Dict, Res};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, ErrorKind, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct CrateData {
   version: String,
   license: String,
@@ -15,37 +15,41 @@ struct CrateData {
   msrv: String, // minimum supported rust version
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(name: String) -> Res<CrateData> {
-  let url = format!("https://crates.io/api/v1/crates/{name}");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
+  cached("crates:get_data", name, |name| async move {
+    let url = format!("https://crates.io/api/v1/crates/{name}");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
 
-  let v1 = dat["crate"]["max_stable_version"].as_str();
-  let v2 = dat["crate"]["max_version"].as_str();
-  let version = v1.or(v2).unwrap_or("unknown").to_string();
+    let v1 = dat["crate"]["max_stable_version"].as_str();
+    let v2 = dat["crate"]["max_version"].as_str();
+    let version = v1.or(v2).unwrap_or("unknown").to_string();
 
-  let dlt = dat["crate"]["downloads"].as_u64().unwrap_or(0);
-  let dlq = dat["crate"]["recent_downloads"].as_u64().unwrap_or(0);
+    let dlt = dat["crate"]["downloads"].as_u64().unwrap_or(0);
+    let dlq = dat["crate"]["recent_downloads"].as_u64().unwrap_or(0);
 
-  let ver_data = dat["versions"]
-    .as_array()
-    .ok_or(anyhow::anyhow!("versions not found"))?
-    .iter()
-    .find(|x| x["num"].as_str() == Some(&version))
-    .ok_or(anyhow::anyhow!("version not found"))?;
-  let license = ver_data["license"].as_str().unwrap_or("unknown").to_string();
-  let msrv = ver_data["rust_version"].as_str().unwrap_or("unknown").to_string();
+    let ver_data = dat["versions"]
+      .as_array()
+      .ok_or(ErrorKind::NotFound.msg("versions not found"))?
+      .iter()
+      .find(|x| x["num"].as_str() == Some(&version))
+      .ok_or(ErrorKind::NotFound.msg("version not found"))?;
+    let license = ver_data["license"].as_str().unwrap_or("unknown").to_string();
+    let msrv = ver_data["rust_version"].as_str().unwrap_or("unknown").to_string();
 
-  Ok(CrateData { version, license, dlt, dlq, msrv })
+    Ok(CrateData { version, license, dlt, dlq, msrv })
+  })
+  .await
 }
 
-#[cached(time = 60, result = true)]
 async fn get_docs(name: String) -> Res<bool> {
-  let url = format!("https://docs.rs/crate/{name}/latest/status.json");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
-  Ok(dat["doc_status"].as_bool().unwrap_or(false))
+  cached("crates:get_docs", name, |name| async move {
+    let url = format!("https://docs.rs/crate/{name}/latest/status.json");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+    Ok(dat["doc_status"].as_bool().unwrap_or(false))
+  })
+  .await
 }
 
 #[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display, PartialEq)]
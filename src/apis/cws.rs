@@ -1,12 +1,12 @@
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, Color};
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::{Badge, Color, utils::render_stars};
-use crate::server::{BadgeRep, Dict, Res};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Data {
   version: String,
   users: String,
@@ -14,26 +14,28 @@ struct Data {
   score_count: u64,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(name: String) -> Res<Data> {
-  // https://github.com/simov/chrome-webstore/blob/master/client.js
-  let url = "https://chrome.google.com/webstore/ajax/detail";
-  let opt = &[("id", name.as_str()), ("hl", "en"), ("pv", "20210820")];
-  let req = get_client().post(url).query(opt);
-  let req = req.body("").header("accept", "*/*").header("content-length", "0").build()?;
-  let rep = get_client().execute(req).await?.error_for_status()?;
-
-  let dat = &rep.text().await?[6..];
-  let dat: serde_json::Value = serde_json::from_str(dat)?;
-  let dat = &dat[1][1]; // detail
-
-  // https://github.com/simov/chrome-webstore/blob/master/map/detail.js
-  let version = dat[6].as_str().unwrap_or("unknown").to_string();
-  let users = dat[0][23].as_str().unwrap_or("0").to_string();
-  let score = dat[0][12].as_f64().unwrap_or(0.0);
-  let score_count = dat[0][22].as_u64().unwrap_or(0);
-
-  Ok(Data { version, users, score, score_count })
+  cached("cws:get_data", name, |name| async move {
+    // https://github.com/simov/chrome-webstore/blob/master/client.js
+    let url = "https://chrome.google.com/webstore/ajax/detail";
+    let opt = &[("id", name.as_str()), ("hl", "en"), ("pv", "20210820")];
+    let req = get_client().post(url).query(opt);
+    let req = req.body("").header("accept", "*/*").header("content-length", "0").build()?;
+    let rep = get_client().execute(req).await?.error_for_status()?;
+
+    let dat = &rep.text().await?[6..];
+    let dat: serde_json::Value = serde_json::from_str(dat)?;
+    let dat = &dat[1][1]; // detail
+
+    // https://github.com/simov/chrome-webstore/blob/master/map/detail.js
+    let version = dat[6].as_str().unwrap_or("unknown").to_string();
+    let users = dat[0][23].as_str().unwrap_or("0").to_string();
+    let score = dat[0][12].as_f64().unwrap_or(0.0);
+    let score_count = dat[0][22].as_u64().unwrap_or(0);
+
+    Ok(Data { version, users, score, score_count })
+  })
+  .await
 }
 
 #[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display)]
@@ -54,9 +56,9 @@ pub async fn handler(Path((kind, name)): Path<(Kind, String)>, Query(qs): Query<
   let rs = get_data(name).await?;
   match kind {
     Kind::Version => Ok(Badge::for_version(&qs, "chrome web store", &rs.version)?),
-    Kind::Rating => Ok(Badge::new("rating", &format!("{:.1}/5", rs.score), Color::DefaultValue)),
+    Kind::Rating => Ok(Badge::for_rating(&qs, "rating", rs.score)?),
     Kind::RatingCount => Ok(Badge::for_count(&qs, "ratings", rs.score_count)?),
-    Kind::Users => Ok(Badge::new("users", &rs.users, Color::DefaultValue)),
-    Kind::Stars => Ok(Badge::new("stars", &render_stars(rs.score, 5.0), Color::DefaultValue)),
+    Kind::Users => Ok(Badge::from_qs_with(&qs, "users", &rs.users, Color::DefaultValue)?),
+    Kind::Stars => Ok(Badge::for_stars(&qs, "stars", rs.score)?),
   }
 }
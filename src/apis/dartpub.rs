@@ -1,57 +1,61 @@
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, DlPeriod};
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::{Badge, DlPeriod};
-use crate::server::{BadgeRep, Dict, Res};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Data {
   version: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Score {
   dlm: u64,
   likes: u64,
   license: String,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(name: String) -> Res<Data> {
-  let url = format!("https://pub.dev/api/packages/{name}");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
+  cached("pub:get_data", name, |name| async move {
+    let url = format!("https://pub.dev/api/packages/{name}");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
 
-  let version = dat["latest"]["version"].as_str().unwrap_or("unknown").to_string();
+    let version = dat["latest"]["version"].as_str().unwrap_or("unknown").to_string();
 
-  Ok(Data { version })
+    Ok(Data { version })
+  })
+  .await
 }
 
-#[cached(time = 60, result = true)]
 async fn get_score(name: String) -> Res<Score> {
-  let url = format!("https://pub.dev/api/packages/{name}/score");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
+  cached("pub:get_score", name, |name| async move {
+    let url = format!("https://pub.dev/api/packages/{name}/score");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
 
-  let dlm = dat["downloadCount30Days"].as_u64().unwrap_or(0);
-  let likes = dat["likeCount"].as_u64().unwrap_or(0);
+    let dlm = dat["downloadCount30Days"].as_u64().unwrap_or(0);
+    let likes = dat["likeCount"].as_u64().unwrap_or(0);
 
-  let default_vec = vec![];
-  let tags = dat["tags"].as_array().unwrap_or(&default_vec);
-  let tags = tags
-    .iter()
-    .filter_map(|x| x.as_str())
-    .filter(|x| {
-      x.starts_with("license:") && *x != "license:fsf-libre" && *x != "license:osi-approved"
-    })
-    .map(|x| x[8..].to_string())
-    .collect::<Vec<String>>();
+    let default_vec = vec![];
+    let tags = dat["tags"].as_array().unwrap_or(&default_vec);
+    let tags = tags
+      .iter()
+      .filter_map(|x| x.as_str())
+      .filter(|x| {
+        x.starts_with("license:") && *x != "license:fsf-libre" && *x != "license:osi-approved"
+      })
+      .map(|x| x[8..].to_string())
+      .collect::<Vec<String>>();
 
-  let license = tags.first().unwrap_or(&"unknown".to_string()).to_string();
+    let license = tags.first().unwrap_or(&"unknown".to_string()).to_string();
 
-  Ok(Score { dlm, likes, license })
+    Ok(Score { dlm, likes, license })
+  })
+  .await
 }
 
 #[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display)]
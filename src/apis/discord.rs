@@ -1,25 +1,26 @@
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, Color};
+use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::{
-  badgelib::{Badge, Color},
-  server::{BadgeRep, Dict, Res},
-};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Data {
   members: u64,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(name: String) -> Res<Data> {
-  let url = format!("https://discord.com/api/v6/guilds/{name}/widget.json");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
+  cached("discord:get_data", name, |name| async move {
+    let url = format!("https://discord.com/api/v6/guilds/{name}/widget.json");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
 
-  let members = dat["presence_count"].as_u64().unwrap_or(0);
-  Ok(Data { members })
+    let members = dat["presence_count"].as_u64().unwrap_or(0);
+    Ok(Data { members })
+  })
+  .await
 }
 
 pub async fn handler(Path(name): Path<String>, Query(qs): Query<Dict>) -> BadgeRep {
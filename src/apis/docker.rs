@@ -1,34 +1,35 @@
-use anyhow::anyhow;
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, Color};
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::{Badge, Color};
+use crate::cache::cached;
 use crate::server::BadgeRep;
-use crate::server::{Dict, Res};
+use crate::server::{Dict, ErrorKind, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Data {
   stars: u64,
   pulls: u64,
   automated: bool,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(name: String) -> Res<Data> {
-  let url = format!("https://hub.docker.com/v2/repositories/{name}");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
-
-  let stars = dat["star_count"].as_u64().unwrap_or(0);
-  let pulls = dat["pull_count"].as_u64().unwrap_or(0);
-  let automated = dat["is_automated"].as_bool().unwrap_or(false);
-
-  Ok(Data { stars, pulls, automated })
+  cached("docker:get_data", name, |name| async move {
+    let url = format!("https://hub.docker.com/v2/repositories/{name}");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+
+    let stars = dat["star_count"].as_u64().unwrap_or(0);
+    let pulls = dat["pull_count"].as_u64().unwrap_or(0);
+    let automated = dat["is_automated"].as_bool().unwrap_or(false);
+
+    Ok(Data { stars, pulls, automated })
+  })
+  .await
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Tags {
   image_tag: String,
   image_size: u64,
@@ -45,7 +46,7 @@ async fn get_tags(name: String, tag: Option<String>) -> Res<Tags> {
   // [(name, [(arch, size), ..]), ..]
   let tags = dat["results"]
     .as_array()
-    .ok_or(anyhow!("no data"))?
+    .ok_or(ErrorKind::NotFound.msg("no tags"))?
     .iter()
     .filter_map(|tag| {
       let name = tag["name"].as_str();
@@ -0,0 +1,101 @@
+use axum::extract::Query;
+use badgelib::{Badge, Thresholds};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_json_path::JsonPath;
+
+use super::{get_client, read_limited};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, ErrorKind, Path, Res};
+
+const MAX_SIZE: usize = 1024 * 1024;
+
+async fn get_doc(url: String) -> Res<String> {
+  cached("dynamic:get_doc", url, |url| async move {
+    let url = reqwest::Url::parse(&url)?;
+    if !matches!(url.scheme(), "http" | "https") {
+      return Err(ErrorKind::InvalidParams.msg(format!("invalid document url: {url}")));
+    }
+
+    let rep = get_client().get(url).send().await?.error_for_status()?;
+    let dat = read_limited(rep, MAX_SIZE).await?;
+    Ok(String::from_utf8(dat)?)
+  })
+  .await
+}
+
+// MARK: Queries
+
+fn to_text(value: &Value) -> String {
+  match value {
+    Value::String(x) => x.clone(),
+    x => x.to_string(),
+  }
+}
+
+fn query_json(doc: &Value, query: &str) -> Res<Vec<String>> {
+  let path = JsonPath::parse(query)
+    .map_err(|e| ErrorKind::InvalidParams.msg(format!("invalid query: {e}")))?;
+  Ok(path.query(doc).all().into_iter().map(to_text).collect())
+}
+
+fn query_xml(doc: &str, query: &str) -> Res<Vec<String>> {
+  use sxd_xpath::Value;
+
+  let pkg = sxd_document::parser::parse(doc)
+    .map_err(|e| ErrorKind::UpstreamParse.msg(format!("invalid xml: {e:?}")))?;
+  let doc = pkg.as_document();
+  let rs = sxd_xpath::evaluate_xpath(&doc, query)?;
+
+  let rs = match rs {
+    Value::Nodeset(x) => x.document_order().iter().map(|x| x.string_value()).collect(),
+    Value::String(x) => vec![x],
+    Value::Number(x) => vec![x.to_string()],
+    Value::Boolean(x) => vec![x.to_string()],
+  };
+
+  Ok(rs)
+}
+
+#[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display, PartialEq)]
+pub(crate) enum Kind {
+  #[serde(rename = "json")]
+  Json,
+  #[serde(rename = "yaml")]
+  Yaml,
+  #[serde(rename = "toml")]
+  Toml,
+  #[serde(rename = "xml")]
+  Xml,
+}
+
+fn query(kind: Kind, doc: &str, query: &str) -> Res<Vec<String>> {
+  match kind {
+    Kind::Json => query_json(&serde_json::from_str(doc)?, query),
+    Kind::Yaml => query_json(&serde_yaml::from_str(doc)?, query),
+    Kind::Toml => query_json(&toml::from_str(doc)?, query),
+    Kind::Xml => query_xml(doc, query),
+  }
+}
+
+// MARK: Handlers
+
+pub async fn handler(Path(kind): Path<Kind>, Query(qs): Query<Dict>) -> BadgeRep {
+  let url = qs.get("url").ok_or_else(|| ErrorKind::InvalidParams.msg("url is required"))?;
+  let expr = qs.get("query").ok_or_else(|| ErrorKind::InvalidParams.msg("query is required"))?;
+
+  let doc = get_doc(url.clone()).await?;
+  let rs = query(kind, &doc, expr)?;
+  if rs.is_empty() {
+    return Err(ErrorKind::NotFound.msg(format!("no result for query: {expr}")).into());
+  }
+
+  let prefix = qs.get("prefix").map_or("", |x| x.as_str());
+  let suffix = qs.get("suffix").map_or("", |x| x.as_str());
+  let value = format!("{prefix}{}{suffix}", rs.join(", "));
+
+  // single numeric result can be colored with `thresholds`
+  let num = if let [x] = &rs[..] { x.trim().parse::<f64>().ok() } else { None };
+  let color = num.and_then(|x| Some(Thresholds::from_qs(&qs)?.color(x))).unwrap_or_default();
+  Ok(Badge::from_qs_with(&qs, "custom badge", &value, color)?)
+}
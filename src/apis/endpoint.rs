@@ -0,0 +1,87 @@
+use axum::extract::Query;
+use badgelib::Badge;
+use serde::{Deserialize, Serialize};
+
+use super::{get_client, read_limited};
+use crate::cache::cached_for;
+use crate::server::{BadgeRep, Dict, ErrorKind, Res};
+
+// Endpoint JSON is a few fields, anything bigger is not a badge
+const MAX_SIZE: usize = 64 * 1024;
+
+// https://shields.io/badges/endpoint-badge
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Endpoint {
+  schema_version: u8,
+  label: Option<String>,
+  message: String,
+  color: Option<String>,
+  label_color: Option<String>,
+  named_logo: Option<String>,
+  logo_color: Option<String>,
+  style: Option<String>,
+  cache_seconds: Option<u32>,
+  #[serde(default)]
+  is_error: bool,
+}
+
+// `cacheSeconds` also keeps upstream JSON cached longer (but not shorter than configured ttl)
+async fn get_data(url: String) -> Res<Endpoint> {
+  let ttl = |x: &Endpoint| x.cache_seconds.map(u64::from);
+  cached_for("endpoint:get_data", url, ttl, |url| async move {
+    let url = reqwest::Url::parse(&url)?;
+    if !matches!(url.scheme(), "http" | "https") {
+      return Err(ErrorKind::InvalidParams.msg(format!("invalid endpoint url: {url}")));
+    }
+
+    let rep = get_client().get(url).send().await?.error_for_status()?;
+    let dat = read_limited(rep, MAX_SIZE).await?;
+    let dat = serde_json::from_slice::<Endpoint>(&dat)
+      .map_err(|e| ErrorKind::UpstreamParse.msg(format!("invalid endpoint json: {e}")))?;
+    if dat.schema_version != 1 {
+      return Err(
+        ErrorKind::UpstreamParse.msg(format!("unsupported schema version: {}", dat.schema_version)),
+      );
+    }
+
+    Ok(dat)
+  })
+  .await
+}
+
+// Endpoint values used as defaults, so any of them can be overridden with query params
+fn to_qs(dat: Endpoint) -> Dict {
+  let color = if dat.is_error { Some("red".to_string()) } else { dat.color };
+
+  let pairs = [
+    ("label", dat.label),
+    ("value", Some(dat.message)),
+    ("color", color),
+    ("labelColor", dat.label_color),
+    ("logo", dat.named_logo),
+    ("logoColor", dat.logo_color),
+    ("style", dat.style),
+    ("cache", dat.cache_seconds.map(|x| x.to_string())),
+  ];
+
+  pairs.into_iter().filter_map(|(k, v)| Some((k.to_string(), v?))).collect()
+}
+
+pub async fn handler(Query(qs): Query<Dict>) -> BadgeRep {
+  let url = qs.get("url").ok_or_else(|| ErrorKind::InvalidParams.msg("url is required"))?;
+
+  let dat = get_data(url.clone()).await?;
+  let is_error = dat.is_error;
+
+  let mut params = to_qs(dat);
+  params.extend(qs.into_iter().filter(|(k, _)| k != "url"));
+
+  // error state is always red, as in shields
+  if is_error {
+    params.remove("rcolor");
+    params.insert("color".to_string(), "red".to_string());
+  }
+
+  Ok(Badge::from_qs(&params)?)
+}
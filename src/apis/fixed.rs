@@ -1,13 +1,13 @@
-use axum::extract::{Path, Query};
+use axum::extract::Query;
+use badgelib::{Badge, Color, Segment};
 
-use crate::badgelib::{Badge, Color};
-use crate::server::{BadgeRep, Dict};
+use crate::server::{BadgeRep, Dict, ErrorKind, Path, Res};
 
 pub async fn handler1(Query(qs): Query<Dict>) -> BadgeRep {
   Ok(Badge::from_qs(&qs)?)
 }
 
-pub async fn handler2(Path(config): Path<String>, Query(qs): Query<Dict>) -> BadgeRep {
+fn parse_config(config: &str) -> Res<(String, String, Color)> {
   // Label, message and color separated by a dash -. For example: `label-message-color`
   // Message and color only, separated by a dash -. For example: `just%20the%20message-8A2BE2`
   // Rules:
@@ -26,11 +26,16 @@ pub async fn handler2(Path(config): Path<String>, Query(qs): Query<Dict>) -> Bad
     1 => ("", parts[0], Color::DefaultValue),
     2 => ("", parts[0], Color::from_str(parts[1]).unwrap_or_default()),
     3 => (parts[0], parts[1], Color::from_str(parts[2]).unwrap_or_default()),
-    _ => return Err(anyhow::anyhow!("Invalid config: {}", config).into()),
+    _ => return Err(ErrorKind::InvalidParams.msg(format!("invalid config: {config}"))),
   };
 
   let label = label.replace(TOKEN_UNDER, "_").replace(TOKEN_DASH, "-");
   let value = value.replace(TOKEN_UNDER, "_").replace(TOKEN_DASH, "-");
+  Ok((label, value, color))
+}
+
+pub async fn handler2(Path(config): Path<String>, Query(qs): Query<Dict>) -> BadgeRep {
+  let (label, value, color) = parse_config(&config)?;
   // println!(">> {:?} {:?} {:?}", label, value, color);
 
   let mut badge = Badge::from_qs(&qs)?;
@@ -44,21 +49,39 @@ pub async fn handler2(Path(config): Path<String>, Query(qs): Query<Dict>) -> Bad
   Ok(badge)
 }
 
+// `/badge/{label}/{value}/{color}` or segments `/badge/{label-value-color}/{label-value-color}/..`
 pub async fn handler3(
-  Path((label, value, color)): Path<(String, String, Color)>,
+  Path((config, rest)): Path<(String, String)>,
   Query(qs): Query<Dict>,
 ) -> BadgeRep {
-  let label = qs.get("label").unwrap_or(&label);
-  let value = qs.get("value").unwrap_or(&value);
-  let color = qs.get("color").map_or(color, |x| Color::from_str(x).unwrap_or_default());
+  let parts = std::iter::once(config.as_str()).chain(rest.split('/')).collect::<Vec<&str>>();
 
-  let mut badge = Badge::from_qs(&qs)?;
-  badge.llabel = Some(label.to_string());
-  badge.rlabel = value.to_string();
-  badge.rcolor = match badge.rcolor {
-    Color::DefaultValue => color,
-    _ => badge.rcolor,
-  };
+  if let [label, value, color] = parts[..]
+    && let Ok(color) = Color::from_str(color)
+  {
+    let label = qs.get("label").map_or(label, |x| x.as_str());
+    let value = qs.get("value").map_or(value, |x| x.as_str());
+    let color = qs.get("color").map_or(color, |x| Color::from_str(x).unwrap_or_default());
+
+    let mut badge = Badge::from_qs(&qs)?;
+    badge.llabel = Some(label.to_string());
+    badge.rlabel = value.to_string();
+    badge.rcolor = match badge.rcolor {
+      Color::DefaultValue => color,
+      _ => badge.rcolor,
+    };
+
+    return Ok(badge);
+  }
+
+  let mut badge = handler2(Path(parts[0].to_string()), Query(qs)).await?;
+  for part in parts.iter().skip(1).filter(|x| !x.is_empty()) {
+    let (label, value, color) = parse_config(part)?;
+    if !label.is_empty() {
+      badge.segments.push(Segment { text: label, color: badge.lcolor.clone() });
+    }
+    badge.segments.push(Segment { text: value, color });
+  }
 
   Ok(badge)
 }
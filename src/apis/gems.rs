@@ -1,13 +1,12 @@
-use anyhow::anyhow;
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, DlPeriod};
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::{Badge, DlPeriod};
-use crate::server::{BadgeRep, Dict, Res};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, ErrorKind, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct GemData {
   version: String,
   license: String,
@@ -15,24 +14,26 @@ struct GemData {
   ruby_ver: String,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(name: String) -> Res<GemData> {
-  // let url = format!("https://rubygems.org/api/v1/gems/{name}.json");
-  let url = format!("https://rubygems.org/api/v1/versions/{name}.json");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
+  cached("gem:get_data", name, |name| async move {
+    // let url = format!("https://rubygems.org/api/v1/gems/{name}.json");
+    let url = format!("https://rubygems.org/api/v1/versions/{name}.json");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
 
-  let vers = dat.as_array().ok_or(anyhow!("no data"))?;
-  let stable = vers.iter().find(|v| !v["prerelease"].as_bool().unwrap_or(false));
-  let latest = stable.or(vers.first()).ok_or(anyhow!("no version"))?;
+    let vers = dat.as_array().ok_or(ErrorKind::UpstreamParse.msg("no versions"))?;
+    let stable = vers.iter().find(|v| !v["prerelease"].as_bool().unwrap_or(false));
+    let latest = stable.or(vers.first()).ok_or(ErrorKind::NotFound.msg("no version"))?;
 
-  let version = latest["number"].as_str().unwrap_or("unknown").to_string();
-  let license = latest["licenses"][0].as_str().unwrap_or("unknown").to_string();
+    let version = latest["number"].as_str().unwrap_or("unknown").to_string();
+    let license = latest["licenses"][0].as_str().unwrap_or("unknown").to_string();
 
-  let dlt = vers.iter().map(|v| v["downloads_count"].as_u64().unwrap_or(0)).sum();
-  let ruby_ver = latest["ruby_version"].as_str().unwrap_or("unknown").to_string();
+    let dlt = vers.iter().map(|v| v["downloads_count"].as_u64().unwrap_or(0)).sum();
+    let ruby_ver = latest["ruby_version"].as_str().unwrap_or("unknown").to_string();
 
-  Ok(GemData { version, license, dlt, ruby_ver })
+    Ok(GemData { version, license, dlt, ruby_ver })
+  })
+  .await
 }
 
 #[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display)]
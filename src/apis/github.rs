@@ -1,19 +1,126 @@
-use anyhow::anyhow;
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use std::sync::{LazyLock, Mutex};
+
+use axum::extract::Query;
+use axum::http::HeaderMap;
+use badgelib::{Badge, Color, DlPeriod};
 use chrono::{DateTime, Utc};
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::Color;
-use crate::badgelib::utils::for_date;
-use crate::server::{Dict, Res};
-use crate::{
-  badgelib::{Badge, DlPeriod},
-  server::BadgeRep,
-};
-
-#[derive(Debug, Clone)]
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, ErrorKind, Path, RateLimitError, Res};
+
+// MARK: Tokens
+
+// Anonymous requests are limited to 60 req/hour per IP, so tokens can be provided with
+// `[github]` config section, `GITHUB_TOKENS` env (comma separated) or `GITHUB_TOKENS_FILE`
+// (one token per line)
+
+#[derive(Debug)]
+struct TokenState {
+  token: Option<String>, // None for anonymous access
+  remaining: Option<u64>,
+  reset: i64,
+}
+
+struct TokenPool(Mutex<Vec<TokenState>>);
+
+static TOKENS: LazyLock<TokenPool> = LazyLock::new(TokenPool::from_config);
+
+impl TokenPool {
+  fn from_config() -> Self {
+    let config = &crate::config::get().github;
+    let mut tokens = config.tokens.join(",");
+
+    if let Some(path) = &config.tokens_file {
+      match std::fs::read_to_string(path) {
+        Ok(data) => tokens.push_str(&format!("\n{data}")),
+        Err(e) => tracing::error!("failed to read {}: {}", path, e),
+      }
+    }
+
+    let mut tokens = tokens
+      .split(|c: char| c == ',' || c.is_whitespace())
+      .map(|x| x.trim())
+      .filter(|x| !x.is_empty() && !x.starts_with('#'))
+      .map(|x| Some(x.to_string()))
+      .collect::<Vec<_>>();
+
+    tokens.sort();
+    tokens.dedup();
+    tracing::info!("github: {} token(s) loaded", tokens.len());
+
+    if tokens.is_empty() {
+      tokens.push(None);
+    }
+
+    let tokens = tokens.into_iter().map(|token| TokenState { token, remaining: None, reset: 0 });
+    TokenPool(Mutex::new(tokens.collect()))
+  }
+
+  // Pick token with the most remaining requests, unknown state considered as fresh
+  fn pick(&self) -> Result<(usize, Option<String>), RateLimitError> {
+    let now = Utc::now().timestamp();
+    let mut tokens = self.0.lock().unwrap();
+
+    for t in tokens.iter_mut().filter(|t| t.reset <= now) {
+      t.remaining = None;
+    }
+
+    let (idx, best) = tokens
+      .iter_mut()
+      .enumerate()
+      .max_by_key(|(_, t)| t.remaining.unwrap_or(u64::MAX))
+      .expect("at least one token");
+
+    if best.remaining == Some(0) {
+      let reset = DateTime::from_timestamp(best.reset, 0);
+      return Err(RateLimitError { reset });
+    }
+
+    // reserve one request, so concurrent requests are spread across tokens
+    best.remaining = best.remaining.map(|x| x.saturating_sub(1));
+    Ok((idx, best.token.clone()))
+  }
+
+  fn update(&self, idx: usize, headers: &HeaderMap) {
+    let get = |name: &str| headers.get(name).and_then(|x| x.to_str().ok()?.parse::<i64>().ok());
+    let (Some(remaining), Some(reset)) = (get("x-ratelimit-remaining"), get("x-ratelimit-reset"))
+    else {
+      return;
+    };
+
+    let mut tokens = self.0.lock().unwrap();
+    if let Some(t) = tokens.get_mut(idx) {
+      t.remaining = Some(remaining.max(0) as u64);
+      t.reset = reset;
+    }
+  }
+}
+
+async fn api_get(url: &str, query: &[(&str, &str)]) -> Res<reqwest::Response> {
+  let (idx, token) = TOKENS.pick()?;
+
+  let req = get_client().get(url).query(query).header("accept", "application/vnd.github+json");
+  let req = if let Some(token) = token { req.bearer_auth(token) } else { req };
+
+  let rep = req.send().await?;
+  TOKENS.update(idx, rep.headers());
+
+  let limited = rep.headers().get("x-ratelimit-remaining").is_some_and(|x| x == "0");
+  if limited && matches!(rep.status(), StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS) {
+    let reset = rep.headers().get("x-ratelimit-reset").and_then(|x| x.to_str().ok()?.parse().ok());
+    let reset = reset.and_then(|x| DateTime::from_timestamp(x, 0));
+    return Err(RateLimitError { reset }.into());
+  }
+
+  Ok(rep.error_for_status()?)
+}
+
+// MARK: Data
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Base {
   license: String,
   stars: u64,
@@ -22,56 +129,61 @@ struct Base {
   size: u64,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(name: String) -> Res<Base> {
-  let url = format!("https://api.github.com/repos/{name}");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
+  cached("github:get_data", name, |name| async move {
+    let url = format!("https://api.github.com/repos/{name}");
+    let rep = api_get(&url, &[]).await?;
+    let dat = rep.json::<serde_json::Value>().await?;
 
-  let license = dat["license"]["spdx_id"].as_str().unwrap_or("unknown").to_string();
-  let stars = dat["stargazers_count"].as_u64().unwrap_or(0);
-  let forks = dat["forks_count"].as_u64().unwrap_or(0);
-  let watchers = dat["watchers_count"].as_u64().unwrap_or(0);
-  let size = dat["size"].as_u64().unwrap_or(0) * 1024; // in bytes
+    let license = dat["license"]["spdx_id"].as_str().unwrap_or("unknown").to_string();
+    let stars = dat["stargazers_count"].as_u64().unwrap_or(0);
+    let forks = dat["forks_count"].as_u64().unwrap_or(0);
+    let watchers = dat["watchers_count"].as_u64().unwrap_or(0);
+    let size = dat["size"].as_u64().unwrap_or(0) * 1024; // in bytes
 
-  Ok(Base { license, stars, forks, watchers, size })
+    Ok(Base { license, stars, forks, watchers, size })
+  })
+  .await
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Release {
   version: String,
   dlt: u64,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_release(name: String) -> Res<Release> {
-  let url = format!("https://api.github.com/repos/{name}/releases/latest");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
+  cached("github:get_release", name, |name| async move {
+    let url = format!("https://api.github.com/repos/{name}/releases/latest");
+    let rep = api_get(&url, &[]).await?;
+    let dat = rep.json::<serde_json::Value>().await?;
 
-  let version = dat["tag_name"].as_str().unwrap_or("unknown").to_string();
-  let dlt = dat["assets"]
-    .as_array()
-    .map(|p| p.iter().filter_map(|x| x["download_count"].as_u64()).sum::<u64>())
-    .unwrap_or(0);
+    let version = dat["tag_name"].as_str().unwrap_or("unknown").to_string();
+    let dlt = dat["assets"]
+      .as_array()
+      .map(|p| p.iter().filter_map(|x| x["download_count"].as_u64()).sum::<u64>())
+      .unwrap_or(0);
 
-  Ok(Release { version, dlt })
+    Ok(Release { version, dlt })
+  })
+  .await
 }
 
-#[cached(time = 60, result = true)]
 async fn last_commit(name: String) -> Res<DateTime<Utc>> {
-  let url = format!("https://api.github.com/repos/{name}/commits");
-  let rep = get_client().get(&url).query(&[("per_page", "1")]);
-  let rep = rep.send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
-
-  dat[0]["commit"]["author"]["date"]
-    .as_str()
-    .and_then(|x| x.parse::<DateTime<Utc>>().ok())
-    .ok_or_else(|| anyhow!("no date"))
+  cached("github:last_commit", name, |name| async move {
+    let url = format!("https://api.github.com/repos/{name}/commits");
+    let rep = api_get(&url, &[("per_page", "1")]).await?;
+    let dat = rep.json::<serde_json::Value>().await?;
+
+    dat[0]["commit"]["author"]["date"]
+      .as_str()
+      .and_then(|x| x.parse::<DateTime<Utc>>().ok())
+      .ok_or_else(|| ErrorKind::NotFound.msg("no commits"))
+  })
+  .await
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct LangData {
   top_lang: String,
   top_percent: f32,
@@ -79,28 +191,32 @@ struct LangData {
   total: u64,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_lang(name: String) -> Res<LangData> {
-  let url = format!("https://api.github.com/repos/{name}/languages");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
+  cached("github:get_lang", name, |name| async move {
+    let url = format!("https://api.github.com/repos/{name}/languages");
+    let rep = api_get(&url, &[]).await?;
+    let dat = rep.json::<serde_json::Value>().await?;
 
-  let mut langs: Vec<(String, u64)> = dat
-    .as_object()
-    .map(|p| p.iter().map(|(k, v)| (k.to_string(), v.as_u64().unwrap_or(0))).collect())
-    .unwrap_or_default();
+    let mut langs: Vec<(String, u64)> = dat
+      .as_object()
+      .map(|p| p.iter().map(|(k, v)| (k.to_string(), v.as_u64().unwrap_or(0))).collect())
+      .unwrap_or_default();
 
-  langs.sort_by_key(|(_, v)| *v);
-  langs.reverse();
+    langs.sort_by_key(|(_, v)| *v);
+    langs.reverse();
 
-  let total = langs.iter().map(|(_, v)| v).sum::<u64>();
-  let top_lang = langs.first().map(|(k, _)| k.clone()).unwrap_or_else(|| "unknown".to_string());
-  let top_percent = langs.first().map(|(_, v)| *v as f32 / total as f32).unwrap_or(0.0);
-  let count = langs.len() as u64;
+    let total = langs.iter().map(|(_, v)| v).sum::<u64>();
+    let top_lang = langs.first().map(|(k, _)| k.clone()).unwrap_or_else(|| "unknown".to_string());
+    let top_percent = langs.first().map(|(_, v)| *v as f32 / total as f32).unwrap_or(0.0);
+    let count = langs.len() as u64;
 
-  Ok(LangData { top_lang, top_percent, count, total })
+    Ok(LangData { top_lang, top_percent, count, total })
+  })
+  .await
 }
 
+// MARK: Handlers
+
 #[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display)]
 pub(crate) enum Kind {
   #[serde(rename = "release")]
@@ -167,10 +283,7 @@ pub async fn handler(
         _ => unreachable!(),
       }
     }
-    Kind::LastCommit => {
-      let (value, color) = for_date(last_commit(name).await?);
-      Ok(Badge::from_qs_with(&qs, "last commit", &value, color)?)
-    }
+    Kind::LastCommit => Ok(Badge::for_date(&qs, "last commit", last_commit(name).await?)?),
   }
 }
 
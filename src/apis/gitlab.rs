@@ -0,0 +1,220 @@
+use axum::extract::Query;
+use badgelib::{Badge, Color};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::get_client;
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, ErrorKind, Path, Res};
+
+const DEFAULT_URL: &str = "https://gitlab.com";
+
+// Self-managed instance can be set with `gitlab_url` query param or `gitlab.url` config
+fn get_base(qs: &Dict) -> String {
+  let base = qs.get("gitlab_url").cloned().or_else(|| crate::config::get().gitlab.url.clone());
+  let base = base.unwrap_or(DEFAULT_URL.to_string());
+  let base = if base.starts_with("http") { base } else { format!("https://{base}") };
+  base.trim_end_matches('/').to_string()
+}
+
+// Project path should be encoded as single segment: `group/project` -> `group%2Fproject`
+fn api_url(base: &str, name: &str, path: &[&str]) -> Res<reqwest::Url> {
+  let mut url = reqwest::Url::parse(base)?;
+  url
+    .path_segments_mut()
+    .map_err(|_| ErrorKind::InvalidParams.msg(format!("invalid gitlab url: {base}")))?
+    .extend(["api", "v4", "projects", name])
+    .extend(path);
+  Ok(url)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Project {
+  license: String,
+  stars: u64,
+  forks: u64,
+  issues: u64,
+}
+
+async fn get_project(base: String, name: String) -> Res<Project> {
+  cached("gitlab:get_project", (base, name), |(base, name)| async move {
+    let url = api_url(&base, &name, &[])?;
+    let rep = get_client().get(url).query(&[("license", "true")]);
+    let rep = rep.send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+
+    let license = dat["license"]["nickname"]
+      .as_str()
+      .or_else(|| dat["license"]["name"].as_str())
+      .unwrap_or("unknown")
+      .to_string();
+    let stars = dat["star_count"].as_u64().unwrap_or(0);
+    let forks = dat["forks_count"].as_u64().unwrap_or(0);
+    let issues = dat["open_issues_count"].as_u64().unwrap_or(0);
+
+    Ok(Project { license, stars, forks, issues })
+  })
+  .await
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Pipeline {
+  status: String,
+  coverage: Option<f64>,
+}
+
+async fn get_pipeline(base: String, name: String, branch: Option<String>) -> Res<Pipeline> {
+  cached("gitlab:get_pipeline", (base, name, branch), |(base, name, branch)| async move {
+    let url = api_url(&base, &name, &["pipelines"])?;
+    let req = get_client().get(url).query(&[("per_page", "1")]);
+    let req = if let Some(ref branch) = branch { req.query(&[("ref", branch)]) } else { req };
+    let rep = req.send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+
+    // coverage is only available in pipeline details
+    let id = dat[0]["id"].as_u64().ok_or_else(|| ErrorKind::NotFound.msg("no pipelines"))?;
+    let url = api_url(&base, &name, &["pipelines", &id.to_string()])?;
+    let rep = get_client().get(url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+
+    let status = dat["status"].as_str().unwrap_or("unknown").to_string();
+    let coverage = dat["coverage"].as_str().and_then(|x| x.parse::<f64>().ok());
+    Ok(Pipeline { status, coverage })
+  })
+  .await
+}
+
+async fn get_release(base: String, name: String) -> Res<String> {
+  cached("gitlab:get_release", (base, name), |(base, name)| async move {
+    let url = api_url(&base, &name, &["releases"])?;
+    let rep = get_client().get(url).query(&[("per_page", "1")]);
+    let rep = rep.send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+    Ok(dat[0]["tag_name"].as_str().unwrap_or("unknown").to_string())
+  })
+  .await
+}
+
+async fn get_tag(base: String, name: String) -> Res<String> {
+  cached("gitlab:get_tag", (base, name), |(base, name)| async move {
+    let url = api_url(&base, &name, &["repository", "tags"])?;
+    let rep = get_client().get(url).query(&[("per_page", "1"), ("order_by", "version")]);
+    let rep = rep.send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+    Ok(dat[0]["name"].as_str().unwrap_or("unknown").to_string())
+  })
+  .await
+}
+
+// `None` when there are more than 10k, GitLab omits total count header then
+async fn get_open_mrs(base: String, name: String) -> Res<Option<u64>> {
+  cached("gitlab:get_open_mrs", (base, name), |(base, name)| async move {
+    let url = api_url(&base, &name, &["merge_requests"])?;
+    let rep = get_client().get(url).query(&[("state", "opened"), ("per_page", "1")]);
+    let rep = rep.send().await?.error_for_status()?;
+
+    let total = rep.headers().get("x-total").and_then(|x| x.to_str().ok()?.parse::<u64>().ok());
+    Ok(total)
+  })
+  .await
+}
+
+async fn last_commit(base: String, name: String, branch: Option<String>) -> Res<DateTime<Utc>> {
+  cached("gitlab:last_commit", (base, name, branch), |(base, name, branch)| async move {
+    let url = api_url(&base, &name, &["repository", "commits"])?;
+    let req = get_client().get(url).query(&[("per_page", "1")]);
+    let req = if let Some(ref branch) = branch { req.query(&[("ref_name", branch)]) } else { req };
+    let rep = req.send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+
+    dat[0]["committed_date"]
+      .as_str()
+      .and_then(|x| x.parse::<DateTime<Utc>>().ok())
+      .ok_or_else(|| ErrorKind::NotFound.msg("no commits"))
+  })
+  .await
+}
+
+fn pipeline_status(status: &str) -> (&str, Color) {
+  match status {
+    "success" => ("passed", Color::Green),
+    "failed" => ("failed", Color::Red),
+    "running" => ("running", Color::Blue),
+    "pending" | "created" | "waiting_for_resource" | "preparing" | "scheduled" => {
+      ("pending", Color::Yellow)
+    }
+    "canceled" => ("canceled", Color::Grey),
+    "skipped" => ("skipped", Color::Grey),
+    "manual" => ("manual", Color::Grey),
+    _ => ("unknown", Color::Grey),
+  }
+}
+
+#[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display, PartialEq)]
+pub(crate) enum Kind {
+  #[serde(rename = "pipeline", alias = "pipeline-status")]
+  Pipeline,
+  #[serde(rename = "coverage", alias = "pipeline-coverage")]
+  Coverage,
+  #[serde(rename = "release")]
+  Release,
+  #[serde(rename = "tag")]
+  Tag,
+  #[serde(rename = "stars")]
+  Stars,
+  #[serde(rename = "forks")]
+  Forks,
+  #[serde(rename = "issues")]
+  Issues,
+  #[serde(rename = "mrs", alias = "merge-requests")]
+  MergeRequests,
+  #[serde(rename = "l", alias = "license")]
+  License,
+  #[serde(rename = "last-commit")]
+  LastCommit,
+}
+
+pub async fn handler(Path((kind, name)): Path<(Kind, String)>, Query(qs): Query<Dict>) -> BadgeRep {
+  let base = get_base(&qs);
+  let branch = qs.get("branch").cloned();
+
+  match kind {
+    Kind::Pipeline | Kind::Coverage => {
+      let rs = get_pipeline(base, name, branch).await?;
+      match kind {
+        Kind::Pipeline => {
+          let (value, color) = pipeline_status(&rs.status);
+          Ok(Badge::from_qs_with(&qs, "pipeline", value, color)?)
+        }
+        Kind::Coverage => match rs.coverage {
+          Some(x) => Ok(Badge::for_percent(&qs, "coverage", x)?),
+          None => Ok(Badge::from_qs_with(&qs, "coverage", "unknown", Color::Grey)?),
+        },
+        _ => unreachable!(),
+      }
+    }
+    Kind::Release => Ok(Badge::for_version(&qs, "release", &get_release(base, name).await?)?),
+    Kind::Tag => Ok(Badge::for_version(&qs, "tag", &get_tag(base, name).await?)?),
+    Kind::Stars | Kind::Forks | Kind::Issues | Kind::License => {
+      let rs = get_project(base, name).await?;
+      match kind {
+        Kind::Stars => Ok(Badge::for_count(&qs, "stars", rs.stars)?),
+        Kind::Forks => Ok(Badge::for_count(&qs, "forks", rs.forks)?),
+        Kind::Issues => Ok(Badge::for_count(&qs, "open issues", rs.issues)?),
+        Kind::License => Ok(Badge::for_license(&qs, &rs.license)?),
+        _ => unreachable!(),
+      }
+    }
+    Kind::MergeRequests => match get_open_mrs(base, name).await? {
+      Some(x) => Ok(Badge::for_count(&qs, "open MRs", x)?),
+      None => {
+        let mut badge = Badge::for_count(&qs, "open MRs", 10_000)?;
+        badge.rlabel = "10k+".to_string();
+        Ok(badge)
+      }
+    },
+    Kind::LastCommit => {
+      Ok(Badge::for_date(&qs, "last commit", last_commit(base, name, branch).await?)?)
+    }
+  }
+}
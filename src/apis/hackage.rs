@@ -1,38 +1,40 @@
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::Badge;
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::Badge;
-use crate::server::{BadgeRep, Dict, Res};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Data {
   version: String,
   license: String,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(name: String) -> Res<Data> {
-  let url = format!("https://hackage.haskell.org/package/{name}/{name}.cabal");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.text().await?;
-
-  let version = dat
-    .lines()
-    .find(|x| x.starts_with("version:"))
-    .map(|x| x.split(':').nth(1).unwrap_or("unknown").trim())
-    .unwrap_or("unknown")
-    .to_string();
-
-  let license = dat
-    .lines()
-    .find(|x| x.starts_with("license:"))
-    .map(|x| x.split(':').nth(1).unwrap_or("unknown").trim())
-    .unwrap_or("unknown")
-    .to_string();
-
-  Ok(Data { version, license })
+  cached("hackage:get_data", name, |name| async move {
+    let url = format!("https://hackage.haskell.org/package/{name}/{name}.cabal");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.text().await?;
+
+    let version = dat
+      .lines()
+      .find(|x| x.starts_with("version:"))
+      .map(|x| x.split(':').nth(1).unwrap_or("unknown").trim())
+      .unwrap_or("unknown")
+      .to_string();
+
+    let license = dat
+      .lines()
+      .find(|x| x.starts_with("license:"))
+      .map(|x| x.split(':').nth(1).unwrap_or("unknown").trim())
+      .unwrap_or("unknown")
+      .to_string();
+
+    Ok(Data { version, license })
+  })
+  .await
 }
 
 #[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display)]
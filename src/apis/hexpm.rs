@@ -1,13 +1,13 @@
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, DlPeriod};
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::{Badge, DlPeriod};
+use crate::cache::cached;
 use crate::server::BadgeRep;
-use crate::server::{Dict, Res};
+use crate::server::{Dict, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Data {
   version: String,
   license: String,
@@ -16,30 +16,32 @@ struct Data {
   dlm: u64,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(name: String) -> Res<Data> {
-  let url = format!("https://hex.pm/api/packages/{name}");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
+  cached("hexpm:get_data", name, |name| async move {
+    let url = format!("https://hex.pm/api/packages/{name}");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
 
-  let version = dat["latest_stable_version"]
-    .as_str()
-    .or_else(|| dat["latest_version"].as_str())
-    .unwrap_or("unknown")
-    .to_string();
+    let version = dat["latest_stable_version"]
+      .as_str()
+      .or_else(|| dat["latest_version"].as_str())
+      .unwrap_or("unknown")
+      .to_string();
 
-  let license = dat["meta"]["licenses"]
-    .as_array()
-    .map(|arr| {
-      arr.iter().map(|lic| lic.as_str().unwrap_or("unknown")).collect::<Vec<_>>().join(" | ")
-    })
-    .unwrap_or("unknown".to_string());
+    let license = dat["meta"]["licenses"]
+      .as_array()
+      .map(|arr| {
+        arr.iter().map(|lic| lic.as_str().unwrap_or("unknown")).collect::<Vec<_>>().join(" | ")
+      })
+      .unwrap_or("unknown".to_string());
 
-  let dlt = dat["downloads"]["all"].as_u64().unwrap_or(0);
-  let dlw = dat["downloads"]["week"].as_u64().unwrap_or(0);
-  let dlm = (dlw as f64 / 7.0 * 30.4375) as u64;
+    let dlt = dat["downloads"]["all"].as_u64().unwrap_or(0);
+    let dlw = dat["downloads"]["week"].as_u64().unwrap_or(0);
+    let dlm = (dlw as f64 / 7.0 * 30.4375) as u64;
 
-  Ok(Data { version, license, dlt, dlw, dlm })
+    Ok(Data { version, license, dlt, dlw, dlm })
+  })
+  .await
 }
 
 #[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display, PartialEq)]
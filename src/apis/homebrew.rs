@@ -1,31 +1,33 @@
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, DlPeriod};
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::{Badge, DlPeriod};
-use crate::server::{BadgeRep, Dict, Res};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Data {
   version: String,
   dlm: u64,
   dly: u64,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(chan: String, name: String) -> Res<Data> {
-  let url = format!("https://formulae.brew.sh/api/{chan}/{name}.json");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
+  cached("homebrew:get_data", (chan, name), |(chan, name)| async move {
+    let url = format!("https://formulae.brew.sh/api/{chan}/{name}.json");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
 
-  let v1 = dat["versions"]["stable"].as_str();
-  let v2 = dat["version"].as_str();
-  let version = v1.or(v2).unwrap_or("unknown").to_string();
-  let dlm = dat["analytics"]["install"]["30d"][&name].as_u64().unwrap_or(0);
-  let dly = dat["analytics"]["install"]["90d"][&name].as_u64().unwrap_or(0);
+    let v1 = dat["versions"]["stable"].as_str();
+    let v2 = dat["version"].as_str();
+    let version = v1.or(v2).unwrap_or("unknown").to_string();
+    let dlm = dat["analytics"]["install"]["30d"][&name].as_u64().unwrap_or(0);
+    let dly = dat["analytics"]["install"]["90d"][&name].as_u64().unwrap_or(0);
 
-  Ok(Data { version, dlm, dly })
+    Ok(Data { version, dlm, dly })
+  })
+  .await
 }
 
 #[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display)]
@@ -1,33 +1,39 @@
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, DlPeriod};
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::{Badge, Color, DlPeriod, utils::render_stars};
-use crate::server::{BadgeRep, Dict, Res};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, ErrorKind, Path, Res};
 
-#[cached(time = 60, result = true)]
 async fn get_version(name: String) -> Res<String> {
-  let url = format!("https://plugins.jetbrains.com/api/plugins/{name}/updates");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
-  Ok(dat[0]["version"].as_str().unwrap_or("unknown").to_string())
+  cached("jetbrains:get_version", name, |name| async move {
+    let url = format!("https://plugins.jetbrains.com/api/plugins/{name}/updates");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+    Ok(dat[0]["version"].as_str().ok_or(ErrorKind::NotFound)?.to_string())
+  })
+  .await
 }
 
-#[cached(time = 60, result = true)]
 async fn get_dlt(name: String) -> Res<u64> {
-  let url = format!("https://plugins.jetbrains.com/api/plugins/{name}");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
-  Ok(dat["downloads"].as_u64().unwrap_or(0))
+  cached("jetbrains:get_dlt", name, |name| async move {
+    let url = format!("https://plugins.jetbrains.com/api/plugins/{name}");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+    Ok(dat["downloads"].as_u64().unwrap_or(0))
+  })
+  .await
 }
 
-#[cached(time = 60, result = true)]
 async fn get_score(name: String) -> Res<f64> {
-  let url = format!("https://plugins.jetbrains.com/api/plugins/{name}/rating");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
-  Ok(dat["meanRating"].as_f64().unwrap_or(0.0))
+  cached("jetbrains:get_score", name, |name| async move {
+    let url = format!("https://plugins.jetbrains.com/api/plugins/{name}/rating");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+    Ok(dat["meanRating"].as_f64().unwrap_or(0.0))
+  })
+  .await
 }
 
 #[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display)]
@@ -46,11 +52,7 @@ pub async fn handler(Path((kind, name)): Path<(Kind, String)>, Query(qs): Query<
   match kind {
     Kind::Version => Ok(Badge::for_version(&qs, "jetbrain plugin", &get_version(name).await?)?),
     Kind::Total => Ok(Badge::for_dl(&qs, DlPeriod::Total, get_dlt(name).await?)?),
-    Kind::Score => {
-      Ok(Badge::new("score", &format!("{:.1}/5", get_score(name).await?), Color::DefaultValue))
-    }
-    Kind::Stars => {
-      Ok(Badge::new("stars", &render_stars(get_score(name).await?, 5.0), Color::DefaultValue))
-    }
+    Kind::Score => Ok(Badge::for_rating(&qs, "score", get_score(name).await?)?),
+    Kind::Stars => Ok(Badge::for_stars(&qs, "stars", get_score(name).await?)?),
   }
 }
@@ -1,5 +1,6 @@
 #![forbid(absolute_paths_not_starting_with_crate)]
 pub(crate) mod amo;
+pub(crate) mod client;
 pub(crate) mod clojars;
 pub(crate) mod cocoapods;
 pub(crate) mod codecov;
@@ -9,9 +10,12 @@ pub(crate) mod cws;
 pub(crate) mod dartpub;
 pub(crate) mod discord;
 pub(crate) mod docker;
+pub(crate) mod dynamic;
+pub(crate) mod endpoint;
 pub(crate) mod fixed;
 pub(crate) mod gems;
 pub(crate) mod github;
+pub(crate) mod gitlab;
 pub(crate) mod hackage;
 pub(crate) mod hexpm;
 pub(crate) mod homebrew;
@@ -25,7 +29,64 @@ pub(crate) mod pypi;
 pub(crate) mod readthedocs;
 pub(crate) mod vscode;
 
-pub(crate) fn get_client() -> reqwest::Client {
-  let ua = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
-  reqwest::Client::builder().user_agent(ua).build().unwrap()
-}
+pub(crate) use client::{get_client, init_client, read_limited};
+
+// Route prefixes, used as integration names in config and metrics
+pub(crate) const INTEGRATIONS: &[&str] = &[
+  "amo",
+  "badge",
+  "clojars",
+  "cocoapods",
+  "codecov",
+  "cpan",
+  "crates",
+  "cws",
+  "discord",
+  "docker",
+  "dynamic",
+  "endpoint",
+  "gem",
+  "github",
+  "gitlab",
+  "hackage",
+  "hexpm",
+  "homebrew",
+  "jetbrains",
+  "npm",
+  "nuget",
+  "packagephobia",
+  "packagist",
+  "pub",
+  "puppetforge",
+  "pypi",
+  "readthedocs",
+  "vscode",
+];
+
+// Icons from simple-icons, their brand color is used for `color=brand` without `icon`
+pub(crate) const BRANDS: &[(&str, &str)] = &[
+  ("amo", "firefoxbrowser"),
+  ("clojars", "clojure"),
+  ("cocoapods", "cocoapods"),
+  ("codecov", "codecov"),
+  ("cpan", "perl"),
+  ("crates", "rust"),
+  ("cws", "googlechrome"),
+  ("discord", "discord"),
+  ("docker", "docker"),
+  ("gem", "rubygems"),
+  ("github", "github"),
+  ("gitlab", "gitlab"),
+  ("hackage", "haskell"),
+  ("hexpm", "elixir"),
+  ("homebrew", "homebrew"),
+  ("jetbrains", "jetbrains"),
+  ("npm", "npm"),
+  ("nuget", "nuget"),
+  ("packagist", "packagist"),
+  ("pub", "dart"),
+  ("puppetforge", "puppet"),
+  ("pypi", "pypi"),
+  ("readthedocs", "readthedocs"),
+  ("vscode", "vscodium"),
+];
@@ -1,46 +1,49 @@
-use anyhow::anyhow;
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, DlPeriod};
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::{Badge, DlPeriod};
-use crate::server::{BadgeRep, Dict, Res};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, ErrorKind, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct NpmData {
   version: String,
   license: String,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(name: String) -> Res<NpmData> {
-  let url = format!("https://unpkg.com/{name}@latest/package.json");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
+  cached("npm:get_data", name, |name| async move {
+    let url = format!("https://unpkg.com/{name}@latest/package.json");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
 
-  let version = dat["version"].as_str().unwrap_or("unknown").to_string();
-  let license = dat["license"].as_str().unwrap_or("unknown").to_string();
-  Ok(NpmData { version, license })
+    let version = dat["version"].as_str().unwrap_or("unknown").to_string();
+    let license = dat["license"].as_str().unwrap_or("unknown").to_string();
+    Ok(NpmData { version, license })
+  })
+  .await
 }
 
-#[cached(time = 60, result = true)]
 async fn get_downloads(name: String, kind: Kind) -> Res<u64> {
-  let url = "https://api.npmjs.org/downloads";
-  let url = match kind {
-    Kind::Weekly => format!("{url}/range/last-week/{name}"),
-    Kind::Monthly => format!("{url}/range/last-month/{name}"),
-    Kind::Total => format!("{url}/range/2005-01-01:2030-01-01/{name}"),
-    _ => unreachable!(),
-  };
+  cached("npm:get_downloads", (name, kind), |(name, kind)| async move {
+    let url = "https://api.npmjs.org/downloads";
+    let url = match kind {
+      Kind::Weekly => format!("{url}/range/last-week/{name}"),
+      Kind::Monthly => format!("{url}/range/last-month/{name}"),
+      Kind::Total => format!("{url}/range/2005-01-01:2030-01-01/{name}"),
+      _ => unreachable!(),
+    };
 
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
 
-  let dls = dat["downloads"].as_array().ok_or(anyhow!("no downloads"))?;
-  let dls = dls.iter().filter_map(|x| x["downloads"].as_u64());
-  let dls = dls.sum::<u64>();
-  Ok(dls)
+    let dls = dat["downloads"].as_array().ok_or(ErrorKind::NotFound.msg("no downloads"))?;
+    let dls = dls.iter().filter_map(|x| x["downloads"].as_u64());
+    let dls = dls.sum::<u64>();
+    Ok(dls)
+  })
+  .await
 }
 
 #[derive(
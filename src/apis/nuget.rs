@@ -1,31 +1,34 @@
 use std::str;
 
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, DlPeriod};
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::{Badge, DlPeriod};
-use crate::server::{BadgeRep, Dict, Res};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, ErrorKind, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Data {
   version: String,
   dlt: u64, // total downloads
 }
 
-#[cached(time = 60, result = true)]
 async fn get_dl(name: String) -> Res<Data> {
-  let name = name.to_lowercase();
-  let url = format!("https://azuresearch-usnc.nuget.org/query?q=packageid:{name}&prerelease=true");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
-  let dat = &dat["data"][0];
-
-  let version = dat["version"].as_str().unwrap_or("unknown").to_string();
-  let dlt = dat["totalDownloads"].as_u64().unwrap_or(0);
-
-  Ok(Data { version, dlt })
+  cached("nuget:get_dl", name, |name| async move {
+    let name = name.to_lowercase();
+    let url =
+      format!("https://azuresearch-usnc.nuget.org/query?q=packageid:{name}&prerelease=true");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+    let dat = &dat["data"][0]; // search responds with empty list for unknown package
+
+    let version = dat["version"].as_str().ok_or(ErrorKind::NotFound)?.to_string();
+    let dlt = dat["totalDownloads"].as_u64().unwrap_or(0);
+
+    Ok(Data { version, dlt })
+  })
+  .await
 }
 
 #[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display)]
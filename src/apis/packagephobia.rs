@@ -1,12 +1,12 @@
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, Color};
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::{Badge, Color};
-use crate::server::{BadgeRep, Dict, Res};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Data {
   publish_pretty: String,
   publish_color: Color,
@@ -14,27 +14,29 @@ struct Data {
   install_color: Color,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(name: String) -> Res<Data> {
-  let url = format!("https://packagephobia.com/v2/api.json?p={name}");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
-
-  let publish_pretty = dat["publish"]["pretty"].as_str().unwrap_or("unknown").to_string();
-  let publish_color = dat["publish"]["color"]
-    .as_str()
-    .and_then(|x| x.strip_prefix("#"))
-    .and_then(|x| Color::from_str(x).ok())
-    .unwrap_or(Color::DefaultValue);
-
-  let install_pretty = dat["install"]["pretty"].as_str().unwrap_or("unknown").to_string();
-  let install_color = dat["install"]["color"]
-    .as_str()
-    .and_then(|x| x.strip_prefix("#"))
-    .and_then(|x| Color::from_str(x).ok())
-    .unwrap_or(Color::DefaultValue);
-
-  Ok(Data { publish_pretty, publish_color, install_pretty, install_color })
+  cached("packagephobia:get_data", name, |name| async move {
+    let url = format!("https://packagephobia.com/v2/api.json?p={name}");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+
+    let publish_pretty = dat["publish"]["pretty"].as_str().unwrap_or("unknown").to_string();
+    let publish_color = dat["publish"]["color"]
+      .as_str()
+      .and_then(|x| x.strip_prefix("#"))
+      .and_then(|x| Color::from_str(x).ok())
+      .unwrap_or(Color::DefaultValue);
+
+    let install_pretty = dat["install"]["pretty"].as_str().unwrap_or("unknown").to_string();
+    let install_color = dat["install"]["color"]
+      .as_str()
+      .and_then(|x| x.strip_prefix("#"))
+      .and_then(|x| Color::from_str(x).ok())
+      .unwrap_or(Color::DefaultValue);
+
+    Ok(Data { publish_pretty, publish_color, install_pretty, install_color })
+  })
+  .await
 }
 
 #[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display)]
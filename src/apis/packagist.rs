@@ -1,14 +1,13 @@
-use anyhow::anyhow;
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, DlPeriod};
 use semver::Version;
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::{Badge, DlPeriod};
-use crate::server::{BadgeRep, Dict, Res};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, ErrorKind, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct PackageData {
   version: String,
   license: String,
@@ -29,28 +28,30 @@ fn parse_versions(
   obj.collect::<Vec<_>>()
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(name: String) -> Res<PackageData> {
-  let url = format!("https://packagist.org/packages/{name}.json");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
-  let dat = dat.get("package").ok_or(anyhow!("package not found"))?;
+  cached("packagist:get_data", name, |name| async move {
+    let url = format!("https://packagist.org/packages/{name}.json");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+    let dat = dat.get("package").ok_or(ErrorKind::NotFound)?;
 
-  let dlt = dat["downloads"]["total"].as_u64().unwrap_or(0);
-  let dld = dat["downloads"]["daily"].as_u64().unwrap_or(0);
-  let dlm = dat["downloads"]["monthly"].as_u64().unwrap_or(0);
+    let dlt = dat["downloads"]["total"].as_u64().unwrap_or(0);
+    let dld = dat["downloads"]["daily"].as_u64().unwrap_or(0);
+    let dlm = dat["downloads"]["monthly"].as_u64().unwrap_or(0);
 
-  let vers = dat["versions"].as_object();
-  let mut vers = vers.map(|x| parse_versions(x)).unwrap_or(vec![]);
-  vers.sort_by(|(a, _), (b, _)| b.cmp(a)); // reverse sort by semver
+    let vers = dat["versions"].as_object();
+    let mut vers = vers.map(|x| parse_versions(x)).unwrap_or(vec![]);
+    vers.sort_by(|(a, _), (b, _)| b.cmp(a)); // reverse sort by semver
 
-  let stub = (Version::new(0, 0, 0), &serde_json::Value::Null);
-  let latest = vers.first().unwrap_or(&stub).1;
-  let version = latest["version"].as_str().unwrap_or("unknown").to_string();
-  let license = latest["license"][0].as_str().unwrap_or("unknown").to_string();
-  let php_ver = latest["require"]["php"].as_str().unwrap_or("unknown").to_string();
+    let stub = (Version::new(0, 0, 0), &serde_json::Value::Null);
+    let latest = vers.first().unwrap_or(&stub).1;
+    let version = latest["version"].as_str().unwrap_or("unknown").to_string();
+    let license = latest["license"][0].as_str().unwrap_or("unknown").to_string();
+    let php_ver = latest["require"]["php"].as_str().unwrap_or("unknown").to_string();
 
-  Ok(PackageData { version, license, dlt, dld, dlm, php_ver })
+    Ok(PackageData { version, license, dlt, dld, dlm, php_ver })
+  })
+  .await
 }
 
 #[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display)]
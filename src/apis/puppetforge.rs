@@ -1,29 +1,31 @@
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, Color, DlPeriod};
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::{Badge, Color, DlPeriod};
-use crate::server::{BadgeRep, Dict, Res};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Data {
   ver: String,
   dlt: u64,
   score: u64,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(name: String) -> Res<Data> {
-  let url = format!("https://forgeapi.puppetlabs.com/v3/modules/{name}");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
-
-  let ver = dat["current_release"]["version"].as_str().unwrap_or("unknown").into();
-  let dlt = dat["downloads"].as_u64().unwrap_or(0);
-  let score = dat["current_release"]["validation_score"].as_u64().unwrap_or_default();
-
-  Ok(Data { ver, dlt, score })
+  cached("puppetforge:get_data", name, |name| async move {
+    let url = format!("https://forgeapi.puppetlabs.com/v3/modules/{name}");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+
+    let ver = dat["current_release"]["version"].as_str().unwrap_or("unknown").into();
+    let dlt = dat["downloads"].as_u64().unwrap_or(0);
+    let score = dat["current_release"]["validation_score"].as_u64().unwrap_or_default();
+
+    Ok(Data { ver, dlt, score })
+  })
+  .await
 }
 
 #[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display)]
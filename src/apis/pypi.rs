@@ -1,13 +1,12 @@
-use anyhow::anyhow;
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, Color, DlPeriod, utils::to_ver_label};
 use serde::{Deserialize, Serialize};
 
 use super::get_client;
-use crate::badgelib::{Badge, Color, DlPeriod, utils::to_ver_label};
-use crate::server::{BadgeRep, Dict, Res};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, ErrorKind, Path, Res};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct PyPiData {
   version: String,
   license: String,
@@ -17,78 +16,85 @@ struct PyPiData {
   implementation: String,
 }
 
-#[cached(time = 60, result = true)]
 async fn get_data(name: String) -> Res<PyPiData> {
-  // https://pypi.org/pypi?%3Aaction=list_classifiers
-  let url = format!("https://pypi.org/pypi/{name}/json");
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
-
-  let version = dat["info"]["version"].as_str().unwrap_or("unknown").into();
-  let license = dat["info"]["license"].as_str().unwrap_or("unknown").into();
-
-  let classifiers = dat["info"]["classifiers"]
-    .as_array()
-    .map(|v| v.iter().filter_map(|x| x.as_str()).collect::<Vec<_>>())
-    .unwrap_or_default();
-
-  let pythons = classifiers
-    .iter()
-    .filter(|v| v.starts_with("Programming Language :: Python :: "))
-    .map(|v| v.replace("Programming Language :: Python :: ", ""))
-    .collect::<Vec<String>>();
-
-  let status = classifiers
-    .iter()
-    .find(|v| v.starts_with("Development Status :: "))
-    .and_then(|v| v.split(" - ").last())
-    .unwrap_or("unknown")
-    .to_lowercase()
-    .replace("production/stable", "stable");
-
-  let formats = dat["releases"][&version]
-    .as_array()
-    .map(|v| v.iter().filter_map(|x| x["packagetype"].as_str()).collect::<Vec<_>>())
-    .unwrap_or_default();
-
-  // let egg = formats.iter().any(|x| *x == "bdist_egg" || *x == "egg");
-  let wheel = formats.iter().any(|x| *x == "bdist_wheel" || *x == "wheel");
-
-  let implementation = classifiers
-    .iter()
-    .filter(|x| x.starts_with("Programming Language :: Python :: Implementation :: "))
-    .map(|x| x.replace("Programming Language :: Python :: Implementation :: ", "").to_lowercase())
-    .collect::<Vec<_>>()
-    .join(" | ");
-
-  let implementation =
-    if implementation.is_empty() { "cpython".to_string() } else { implementation };
-
-  Ok(PyPiData { version, license, pythons, wheel, status, implementation })
+  cached("pypi:get_data", name, |name| async move {
+    // https://pypi.org/pypi?%3Aaction=list_classifiers
+    let url = format!("https://pypi.org/pypi/{name}/json");
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+
+    let version = dat["info"]["version"].as_str().unwrap_or("unknown").into();
+    let license = dat["info"]["license"].as_str().unwrap_or("unknown").into();
+
+    let classifiers = dat["info"]["classifiers"]
+      .as_array()
+      .map(|v| v.iter().filter_map(|x| x.as_str()).collect::<Vec<_>>())
+      .unwrap_or_default();
+
+    let pythons = classifiers
+      .iter()
+      .filter(|v| v.starts_with("Programming Language :: Python :: "))
+      .map(|v| v.replace("Programming Language :: Python :: ", ""))
+      .filter(|v| v.starts_with(|c: char| c.is_ascii_digit())) // skip `Implementation :: PyPy`
+      .collect::<Vec<String>>();
+
+    let status = classifiers
+      .iter()
+      .find(|v| v.starts_with("Development Status :: "))
+      .and_then(|v| v.split(" - ").last())
+      .unwrap_or("unknown")
+      .to_lowercase()
+      .replace("production/stable", "stable");
+
+    let formats = dat["releases"][&version]
+      .as_array()
+      .map(|v| v.iter().filter_map(|x| x["packagetype"].as_str()).collect::<Vec<_>>())
+      .unwrap_or_default();
+
+    // let egg = formats.iter().any(|x| *x == "bdist_egg" || *x == "egg");
+    let wheel = formats.iter().any(|x| *x == "bdist_wheel" || *x == "wheel");
+
+    let implementation = classifiers
+      .iter()
+      .filter(|x| x.starts_with("Programming Language :: Python :: Implementation :: "))
+      .map(|x| x.replace("Programming Language :: Python :: Implementation :: ", "").to_lowercase())
+      .collect::<Vec<_>>()
+      .join(" | ");
+
+    let implementation =
+      if implementation.is_empty() { "cpython".to_string() } else { implementation };
+
+    Ok(PyPiData { version, license, pythons, wheel, status, implementation })
+  })
+  .await
 }
 
-#[cached(time = 60, result = true)]
 async fn get_dl_granular(name: String) -> Res<(u64, u64)> {
-  // doc: https://pypistats.org/api/
-  let url = format!("https://pypistats.org/api/packages/{}/recent", name);
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
-
-  let dlw = dat["data"]["last_week"].as_u64().unwrap_or(0);
-  let dlm = dat["data"]["last_month"].as_u64().unwrap_or(0);
-  Ok((dlw, dlm))
+  cached("pypi:get_dl_granular", name, |name| async move {
+    // doc: https://pypistats.org/api/
+    let url = format!("https://pypistats.org/api/packages/{}/recent", name);
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+
+    let dlw = dat["data"]["last_week"].as_u64().unwrap_or(0);
+    let dlm = dat["data"]["last_month"].as_u64().unwrap_or(0);
+    Ok((dlw, dlm))
+  })
+  .await
 }
 
-#[cached(time = 60, result = true)]
 async fn get_dl_total(name: String) -> Res<u64> {
-  let url = format!("https://pypistats.org/api/packages/{}/overall?mirrors=true", name);
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.json::<serde_json::Value>().await?;
-
-  let dlt = dat["data"].as_array().ok_or(anyhow!("no data"))?;
-  let dlt = dlt.iter().filter_map(|x| x["downloads"].as_u64());
-  let dlt = dlt.sum::<u64>();
-  Ok(dlt)
+  cached("pypi:get_dl_total", name, |name| async move {
+    let url = format!("https://pypistats.org/api/packages/{}/overall?mirrors=true", name);
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.json::<serde_json::Value>().await?;
+
+    let dlt = dat["data"].as_array().ok_or(ErrorKind::NotFound.msg("no downloads"))?;
+    let dlt = dlt.iter().filter_map(|x| x["downloads"].as_u64());
+    let dlt = dlt.sum::<u64>();
+    Ok(dlt)
+  })
+  .await
 }
 
 #[derive(Debug, Deserialize, Serialize, strum::EnumIter, strum::Display)]
@@ -1,17 +1,19 @@
-use axum::extract::{Path, Query};
-use cached::proc_macro::cached;
+use axum::extract::Query;
+use badgelib::{Badge, Color};
 
 use super::get_client;
-use crate::badgelib::{Badge, Color};
-use crate::server::{BadgeRep, Dict, Res};
+use crate::cache::cached;
+use crate::server::{BadgeRep, Dict, Path, Res};
 
-#[cached(time = 60, result = true)]
 async fn get_docs(name: String) -> Res<bool> {
-  // https://readthedocs.org/api/v3/projects/{}/builds/
-  let url = format!("https://readthedocs.org/projects/{}/badge/", name);
-  let rep = get_client().get(&url).send().await?.error_for_status()?;
-  let dat = rep.text().await?;
-  Ok(dat.contains("passing"))
+  cached("readthedocs:get_docs", name, |name| async move {
+    // https://readthedocs.org/api/v3/projects/{}/builds/
+    let url = format!("https://readthedocs.org/projects/{}/badge/", name);
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let dat = rep.text().await?;
+    Ok(dat.contains("passing"))
+  })
+  .await
 }
 
 pub async fn handler(Path(name): Path<String>, Query(qs): Query<Dict>) -> BadgeRep {
@@ -0,0 +1,601 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock};
+use std::time::Duration;
+
+use anyhow::anyhow;
+use axum::extract::Request;
+use axum::http::header;
+use axum::middleware::Next;
+use axum::response::Response;
+use chrono::{DateTime, TimeDelta, Utc};
+use lru::LruCache;
+use redb::TableDefinition;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use tokio::sync::{Semaphore, mpsc};
+
+use crate::metrics::METRICS;
+use crate::server::{ErrorKind, Res};
+
+// MARK: Config
+
+struct CacheConfig {
+  ttl: TimeDelta,                   // how long value considered fresh
+  ttls: HashMap<String, TimeDelta>, // per integration ttl, e.g. `[integrations.npm] ttl = 300`
+  stale_ttl: TimeDelta,             // how long value can be served if upstream fails
+  max_entries: usize,               // values kept in memory, least recently used are dropped
+  timeout: Duration,                // max time to wait for upstream
+  refresh_ahead: TimeDelta,         // refetch hot values this long before expiry (0 to disable)
+  refresh_idle: TimeDelta,          // value is hot if requested within this time
+  refresh_limit: usize,             // max background refreshes per integration
+  backend: String,                  // memory, disk or redis
+  path: String,                     // disk backend file
+  redis_url: String,
+}
+
+fn secs(x: u64) -> TimeDelta {
+  TimeDelta::seconds(x as i64)
+}
+
+static CONFIG: LazyLock<CacheConfig> = LazyLock::new(|| {
+  let config = crate::config::get();
+  let c = &config.cache;
+  let ttls = config.integrations.iter().filter_map(|(k, v)| Some((k.clone(), secs(v.ttl?))));
+
+  CacheConfig {
+    ttl: secs(c.ttl),
+    ttls: ttls.collect(),
+    stale_ttl: secs(c.stale_ttl),
+    max_entries: c.max_entries,
+    timeout: Duration::from_secs(c.timeout),
+    refresh_ahead: secs(c.refresh_ahead),
+    refresh_idle: secs(c.refresh_idle),
+    refresh_limit: c.refresh_limit,
+    backend: c.backend.clone(),
+    path: c.path.clone(),
+    redis_url: c.redis_url.clone(),
+  }
+});
+
+// `ns` is `{integration}:{fetcher}`
+fn integration(ns: &str) -> &str {
+  ns.split(':').next().unwrap_or_default()
+}
+
+fn ttl_for(ns: &str) -> TimeDelta {
+  CONFIG.ttls.get(integration(ns)).copied().unwrap_or(CONFIG.ttl)
+}
+
+// MARK: Request tracking
+
+tokio::task_local! {
+  static STALE: Cell<Option<DateTime<Utc>>>;
+  static FETCHED: Cell<Option<DateTime<Utc>>>;
+}
+
+// Tracks if any stale value was used during request (see `stale_since`) and when used values
+// were fetched, the latest one is sent as `Last-Modified` of badge
+pub async fn track_request(req: Request, next: Next) -> Response {
+  let run = async { (next.run(req).await, FETCHED.with(|x| x.get())) };
+  let (mut rep, fetched) = STALE.scope(Cell::new(None), FETCHED.scope(Cell::new(None), run)).await;
+
+  let failed = rep.extensions().get::<crate::server::BadgeFailed>().is_some();
+  if let Some(at) = fetched
+    && !failed
+    && let Ok(value) = at.format("%a, %d %b %Y %H:%M:%S GMT").to_string().parse()
+  {
+    rep.headers_mut().insert(header::LAST_MODIFIED, value);
+  }
+
+  rep
+}
+
+// Time of the oldest successful fetch used to build response, if some value was stale
+pub fn stale_since() -> Option<DateTime<Utc>> {
+  STALE.try_with(|x| x.get()).ok().flatten()
+}
+
+fn mark_stale(at: DateTime<Utc>) {
+  track(Event::Stale);
+  let _ = STALE.try_with(|x| x.set(Some(x.get().map_or(at, |y| y.min(at)))));
+}
+
+fn mark_fetched(at: DateTime<Utc>) {
+  let _ = FETCHED.try_with(|x| x.set(Some(x.get().map_or(at, |y| y.max(at)))));
+}
+
+// MARK: Stats
+
+// Exported as `badges_cache_events_total`
+#[derive(Debug, Clone, Copy, strum::Display, strum::EnumIter)]
+#[strum(serialize_all = "lowercase")]
+enum Event {
+  Hit,       // fresh value returned from cache
+  Miss,      // upstream fetched
+  Coalesced, // awaited fetch started by another request
+  Stale,     // stale value served after upstream failure
+  Refreshed, // fetched in background before expiry
+}
+
+fn track(event: Event) {
+  METRICS.cache.with_label_values(&[&event.to_string()]).inc();
+}
+
+pub fn stats() -> serde_json::Value {
+  let items = Event::iter().map(|x| {
+    let value = METRICS.cache.with_label_values(&[&x.to_string()]).get();
+    (x.to_string(), serde_json::Value::from(value))
+  });
+
+  serde_json::Value::Object(items.collect())
+}
+
+// MARK: Backends
+
+// Serialized form of cached value, used by persistent backends
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Raw {
+  fetched_at: DateTime<Utc>,
+  value: serde_json::Value,
+}
+
+const TABLE: TableDefinition<&str, &[u8]> = TableDefinition::new("cache");
+
+#[derive(Clone)]
+enum Backend {
+  Disk(Arc<redb::Database>),
+  Redis(redis::aio::ConnectionManager),
+}
+
+struct Persist {
+  backend: Backend,
+  queue: mpsc::UnboundedSender<(String, Raw)>,
+}
+
+static PERSIST: OnceLock<Persist> = OnceLock::new();
+
+impl Backend {
+  async fn open() -> Res<Option<Backend>> {
+    match CONFIG.backend.as_str() {
+      "memory" => Ok(None),
+      "disk" => Ok(Some(Backend::Disk(Arc::new(redb::Database::create(&CONFIG.path)?)))),
+      "redis" => {
+        let client = redis::Client::open(CONFIG.redis_url.as_str())?;
+        Ok(Some(Backend::Redis(client.get_connection_manager().await?)))
+      }
+      x => Err(anyhow!("unknown cache backend: {x}")),
+    }
+  }
+
+  // Disk values are loaded on boot, redis is shared between instances and read on demand
+  async fn load(&self) -> Res<Vec<(String, Raw)>> {
+    let Backend::Disk(db) = self else { return Ok(vec![]) };
+
+    let db = db.clone();
+    tokio::task::spawn_blocking(move || {
+      let now = Utc::now();
+      let mut items = vec![];
+
+      let tx = db.begin_write()?;
+      {
+        let mut table = tx.open_table(TABLE)?;
+        table.retain(|k, v| match serde_json::from_slice::<Raw>(v) {
+          Ok(raw) if now - raw.fetched_at < CONFIG.stale_ttl => {
+            items.push((k.to_string(), raw));
+            true
+          }
+          _ => false,
+        })?;
+      }
+      tx.commit()?;
+
+      Ok(items)
+    })
+    .await?
+  }
+
+  async fn get(&self, key: &str) -> Option<Raw> {
+    let Backend::Redis(con) = self else { return None };
+
+    let rep: Option<Vec<u8>> = redis::AsyncCommands::get(&mut con.clone(), key)
+      .await
+      .inspect_err(|e| tracing::warn!("cache get failed: {:?}", e))
+      .ok()?;
+
+    serde_json::from_slice(&rep?).ok()
+  }
+
+  async fn write(&self, items: Vec<(String, Raw)>) -> Res {
+    match self {
+      Backend::Disk(db) => {
+        let db = db.clone();
+        tokio::task::spawn_blocking(move || {
+          let tx = db.begin_write()?;
+          {
+            let mut table = tx.open_table(TABLE)?;
+            for (key, raw) in items {
+              table.insert(key.as_str(), serde_json::to_vec(&raw)?.as_slice())?;
+            }
+          }
+          tx.commit()?;
+          Ok(())
+        })
+        .await?
+      }
+      Backend::Redis(con) => {
+        let mut pipe = redis::pipe();
+        let ttl = CONFIG.stale_ttl.num_seconds() as u64;
+        for (key, raw) in items {
+          pipe.set_ex(key, serde_json::to_vec(&raw)?, ttl).ignore();
+        }
+        pipe.query_async::<()>(&mut con.clone()).await?;
+        Ok(())
+      }
+    }
+  }
+}
+
+// Starts background refresh, opens configured backend and warms up memory store
+pub async fn init() -> Res {
+  if CONFIG.refresh_ahead > TimeDelta::zero() {
+    tokio::spawn(refresh_loop());
+  }
+
+  let Some(backend) = Backend::open().await? else { return Ok(()) };
+
+  let items = backend.load().await?;
+  tracing::info!("cache backend: {}, loaded {} values", CONFIG.backend, items.len());
+  // oldest go first, so the newest are kept if there are more than `max_entries`
+  let mut items = items;
+  items.sort_by_key(|(_, x)| x.fetched_at);
+  for (key, x) in items {
+    STORE.insert_entry(key, Entry { value: Value::Raw(x.value), fetched_at: x.fetched_at });
+  }
+
+  // writes are batched in background, so requests never wait for persistence
+  let (queue, mut rx) = mpsc::unbounded_channel::<(String, Raw)>();
+  let writer = backend.clone();
+  tokio::spawn(async move {
+    let mut items = vec![];
+    while rx.recv_many(&mut items, 256).await > 0 {
+      if let Err(e) = writer.write(std::mem::take(&mut items)).await {
+        tracing::error!("cache write failed: {:?}", e);
+      }
+    }
+  });
+
+  let _ = PERSIST.set(Persist { backend, queue });
+  Ok(())
+}
+
+// MARK: Store
+
+enum Value {
+  Typed(Arc<dyn Any + Send + Sync>),
+  Raw(serde_json::Value), // loaded from backend, type is known on first access
+}
+
+struct Entry {
+  value: Value,
+  fetched_at: DateTime<Utc>,
+}
+
+// Keys contain user given urls and params (`/endpoint`, `/dynamic`), so size is capped
+struct Store {
+  entries: Mutex<LruCache<String, Entry>>,
+  pruned_at: Mutex<DateTime<Utc>>,
+}
+
+static STORE: LazyLock<Store> = LazyLock::new(|| Store {
+  entries: Mutex::new(match NonZeroUsize::new(CONFIG.max_entries) {
+    Some(size) => LruCache::new(size),
+    None => LruCache::unbounded(),
+  }),
+  pruned_at: Mutex::new(Utc::now()),
+});
+
+impl Store {
+  fn get<T: Clone + Send + Sync + DeserializeOwned + 'static>(
+    &self,
+    key: &str,
+  ) -> Option<(T, DateTime<Utc>)> {
+    let mut entries = self.entries.lock().unwrap();
+    let entry = entries.get_mut(key)?;
+
+    if let Value::Raw(raw) = &entry.value {
+      let value = serde_json::from_value::<T>(raw.clone()).ok()?;
+      entry.value = Value::Typed(Arc::new(value));
+    }
+
+    let Value::Typed(value) = &entry.value else { return None };
+    Some((value.downcast_ref::<T>()?.clone(), entry.fetched_at))
+  }
+
+  fn fetched_at(&self, key: &str) -> Option<DateTime<Utc>> {
+    self.entries.lock().unwrap().peek(key).map(|x| x.fetched_at)
+  }
+
+  fn insert<T: Send + Sync + 'static>(&self, key: String, value: T, fetched_at: DateTime<Utc>) {
+    self.insert_entry(key, Entry { value: Value::Typed(Arc::new(value)), fetched_at });
+  }
+
+  fn insert_entry(&self, key: String, entry: Entry) {
+    self.entries.lock().unwrap().put(key, entry);
+  }
+
+  fn set<T: Serialize + Send + Sync + 'static>(&self, key: String, value: T) {
+    let now = Utc::now();
+
+    if let Some(persist) = PERSIST.get() {
+      match serde_json::to_value(&value) {
+        Ok(x) => drop(persist.queue.send((key.clone(), Raw { fetched_at: now, value: x }))),
+        Err(e) => tracing::warn!("cache serialize failed {}: {:?}", key, e),
+      }
+    }
+
+    self.insert(key, value, now);
+
+    // drop values which can not be served anymore, not more often than once per minute
+    let mut pruned_at = self.pruned_at.lock().unwrap();
+    if now - *pruned_at > TimeDelta::minutes(1) {
+      *pruned_at = now;
+      let mut entries = self.entries.lock().unwrap();
+      let expired = entries.iter().filter(|(_, x)| now - x.fetched_at >= CONFIG.stale_ttl);
+      for key in expired.map(|(k, _)| k.clone()).collect::<Vec<_>>() {
+        entries.pop(&key);
+      }
+    }
+  }
+}
+
+// MARK: Single flight
+
+// Fetch result shared between all requests waiting for the same key
+type Flight = Arc<tokio::sync::OnceCell<Result<Arc<dyn Any + Send + Sync>, Arc<anyhow::Error>>>>;
+
+static INFLIGHT: LazyLock<Mutex<HashMap<String, Flight>>> =
+  LazyLock::new(|| Mutex::new(HashMap::new()));
+
+// Upstream error can't be cloned, so every waiter gets a reference to the original one
+#[derive(Debug)]
+pub struct SharedError(Arc<anyhow::Error>);
+
+impl std::fmt::Display for SharedError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+impl std::error::Error for SharedError {}
+
+impl SharedError {
+  // Original error to inspect with `downcast_ref`
+  pub fn unwrap(e: &anyhow::Error) -> &anyhow::Error {
+    e.downcast_ref::<SharedError>().map_or(e, |x| &x.0)
+  }
+}
+
+// Concurrent calls for the same key await single upstream request, returns if call was a leader
+async fn fly<T, Fut>(key: &str, fetch: Fut) -> (Res<T>, bool)
+where
+  T: Clone + Send + Sync + Serialize + 'static,
+  Fut: Future<Output = Res<T>>,
+{
+  let flight = INFLIGHT.lock().unwrap().entry(key.to_string()).or_default().clone();
+  let leader = AtomicBool::new(false);
+  let rep = flight
+    .get_or_init(|| async {
+      leader.store(true, Ordering::Relaxed);
+      let rep = match tokio::time::timeout(CONFIG.timeout, fetch).await {
+        Ok(rep) => rep,
+        Err(_) => Err(ErrorKind::UpstreamTimeout.into()),
+      };
+
+      match rep {
+        Ok(value) => {
+          STORE.set(key.to_string(), value.clone());
+          Ok(Arc::new(value) as Arc<dyn Any + Send + Sync>)
+        }
+        Err(e) => Err(Arc::new(e)),
+      }
+    })
+    .await
+    .clone();
+
+  {
+    let mut inflight = INFLIGHT.lock().unwrap();
+    if inflight.get(key).is_some_and(|x| Arc::ptr_eq(x, &flight)) {
+      inflight.remove(key);
+    }
+  }
+
+  let rep = match rep {
+    Ok(x) => x.downcast_ref::<T>().cloned().ok_or_else(|| anyhow!("cache type mismatch: {key}")),
+    Err(e) => Err(anyhow::Error::new(SharedError(e))),
+  };
+
+  (rep, leader.load(Ordering::Relaxed))
+}
+
+// MARK: Refresh ahead
+
+// Returns if refresh succeeded
+type Refresh = Arc<dyn Fn() -> Pin<Box<dyn Future<Output = bool> + Send>> + Send + Sync>;
+
+struct Hot {
+  ttl: TimeDelta,
+  refresh: Refresh,
+  accessed_at: DateTime<Utc>,
+  running: Arc<AtomicBool>,
+  failures: u32,                   // refreshes failed in a row
+  retry_at: Option<DateTime<Utc>>, // next refresh after failure
+}
+
+static HOT: LazyLock<Mutex<HashMap<String, Hot>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static LIMITS: LazyLock<Mutex<HashMap<String, Arc<Semaphore>>>> =
+  LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn touch(key: &str, ttl: TimeDelta, refresh: impl FnOnce() -> Refresh) {
+  let now = Utc::now();
+  let mut hot = HOT.lock().unwrap();
+  match hot.get_mut(key) {
+    Some(x) => (x.accessed_at, x.ttl) = (now, ttl),
+    None => {
+      let running = Arc::new(AtomicBool::new(false));
+      let (failures, retry_at) = (0, None);
+      let x = Hot { ttl, refresh: refresh(), accessed_at: now, running, failures, retry_at };
+      hot.insert(key.to_string(), x);
+    }
+  }
+}
+
+// Failing upstream is retried after 2s, 4s, 8s... (up to `refresh_idle`), not on every tick
+fn record_refresh(key: &str, ok: bool) {
+  let mut hot = HOT.lock().unwrap();
+  let Some(x) = hot.get_mut(key) else { return };
+
+  (x.failures, x.retry_at) = match ok {
+    true => (0, None),
+    false => {
+      let delay = secs(1 << x.failures.min(16)).min(CONFIG.refresh_idle);
+      (x.failures + 1, Some(Utc::now() + delay))
+    }
+  };
+}
+
+// Refetches values requested recently shortly before they expire, so hot badges never wait
+async fn refresh_loop() {
+  let mut interval = tokio::time::interval(Duration::from_secs(1));
+  loop {
+    interval.tick().await;
+
+    let now = Utc::now();
+    let mut hot = HOT.lock().unwrap();
+    hot.retain(|_, x| now - x.accessed_at < CONFIG.refresh_idle);
+
+    for (key, x) in hot.iter() {
+      let expires_at = STORE.fetched_at(key).map(|t| t + x.ttl);
+      if expires_at.is_none_or(|t| t - now > CONFIG.refresh_ahead)
+        || x.retry_at.is_some_and(|t| t > now)
+        || x.running.swap(true, Ordering::Relaxed)
+      {
+        continue;
+      }
+
+      let limit = LIMITS
+        .lock()
+        .unwrap()
+        .entry(integration(key).to_string())
+        .or_insert_with(|| Arc::new(Semaphore::new(CONFIG.refresh_limit)))
+        .clone();
+
+      let (key, refresh, running) = (key.clone(), x.refresh.clone(), x.running.clone());
+      tokio::spawn(async move {
+        let _permit = limit.acquire_owned().await;
+        record_refresh(&key, refresh().await);
+        running.store(false, Ordering::Relaxed);
+      });
+    }
+  }
+}
+
+// MARK: Public API
+
+// Returns cached value if fresh, otherwise fetches new one. If upstream fails or times out,
+// last successful value is served (if not older than stale ttl) and request marked as stale.
+// `ns` should be unique per fetcher, it's used as key prefix and value type can't be mixed.
+pub async fn cached<A, T, F, Fut>(ns: &'static str, args: A, fetch: F) -> Res<T>
+where
+  A: Debug + Clone + Send + Sync + 'static,
+  T: Clone + Send + Sync + Serialize + DeserializeOwned + 'static,
+  F: Fn(A) -> Fut + Clone + Send + Sync + 'static,
+  Fut: Future<Output = Res<T>> + Send + 'static,
+{
+  cached_for(ns, args, |_| None, fetch).await
+}
+
+// Same as `cached`, but value can extend own ttl in seconds (e.g. `cacheSeconds` of endpoint),
+// values below integration ttl are ignored
+pub async fn cached_for<A, T, F, Fut>(
+  ns: &'static str,
+  args: A,
+  ttl: fn(&T) -> Option<u64>,
+  fetch: F,
+) -> Res<T>
+where
+  A: Debug + Clone + Send + Sync + 'static,
+  T: Clone + Send + Sync + Serialize + DeserializeOwned + 'static,
+  F: Fn(A) -> Fut + Clone + Send + Sync + 'static,
+  Fut: Future<Output = Res<T>> + Send + 'static,
+{
+  let key = format!("{ns}:{args:?}");
+  let mut last = STORE.get::<T>(&key);
+
+  // value can be already fetched by another instance sharing the backend
+  if last.is_none()
+    && let Some(persist) = PERSIST.get()
+    && let Some(raw) = persist.backend.get(&key).await
+    && let Ok(value) = serde_json::from_value::<T>(raw.value)
+  {
+    STORE.insert(key.clone(), value.clone(), raw.fetched_at);
+    last = Some((value, raw.fetched_at));
+  }
+
+  let ttl_of = |value: &T| {
+    let base = ttl_for(ns);
+    ttl(value).map_or(base, |x| secs(x).max(base).min(CONFIG.stale_ttl.max(base)))
+  };
+
+  if CONFIG.refresh_ahead > TimeDelta::zero() {
+    let ttl = last.as_ref().map_or(ttl_for(ns), |(x, _)| ttl_of(x));
+    touch(&key, ttl, || {
+      let (key, args, fetch) = (key.clone(), args.clone(), fetch.clone());
+      Arc::new(move || {
+        let (key, args, fetch) = (key.clone(), args.clone(), fetch.clone());
+        Box::pin(async move {
+          let (rep, leader) = fly(&key, fetch(args)).await;
+          match &rep {
+            Ok(_) if leader => track(Event::Refreshed),
+            Err(e) => tracing::warn!("refresh failed {}: {:?}", key, e),
+            _ => {}
+          }
+          rep.is_ok()
+        })
+      })
+    });
+  }
+
+  if let Some((value, fetched_at)) = &last
+    && Utc::now() - *fetched_at < ttl_of(value)
+  {
+    track(Event::Hit);
+    mark_fetched(*fetched_at);
+    return Ok(value.clone());
+  }
+
+  let (rep, leader) = fly(&key, fetch(args)).await;
+  track(if leader { Event::Miss } else { Event::Coalesced });
+
+  match (rep, last) {
+    (Ok(value), _) => {
+      mark_fetched(STORE.fetched_at(&key).unwrap_or_else(Utc::now));
+      Ok(value)
+    }
+    (Err(e), Some((value, fetched_at))) if Utc::now() - fetched_at < CONFIG.stale_ttl => {
+      tracing::warn!("serving stale {} from {}: {:?}", key, fetched_at, e);
+      mark_stale(fetched_at);
+      mark_fetched(fetched_at);
+      Ok(value)
+    }
+    (Err(e), _) => Err(e),
+  }
+}
@@ -0,0 +1,131 @@
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use axum::Router;
+use axum::body::Body;
+use axum::http::Request;
+use badgelib::Badge;
+use clap::{Args, Parser, Subcommand};
+use tower::ServiceExt;
+
+use crate::server::{BadgeFailed, Dict, Res};
+
+#[derive(Debug, Parser)]
+#[command(version, about = "Badges for your projects")]
+pub struct Cli {
+  /// Path to TOML config file
+  #[arg(short, long, global = true, env = "BADGES_CONFIG")]
+  pub config: Option<String>,
+
+  #[command(subcommand)]
+  pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+  /// Run http server (default)
+  Serve,
+  /// Render static badge, e.g. `badges render --label build --value passing -o out.svg`
+  Render(RenderArgs),
+  /// Run integration once, e.g. `badges fetch npm dw react -o dl.svg`
+  Fetch(FetchArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct Output {
+  /// Output file, format is taken from extension: svg, json, png or webp (stdout svg if not set)
+  #[arg(short, long)]
+  output: Option<PathBuf>,
+
+  /// Extra badge options as `key=value`, e.g. `-q style=flat-square -q icon=rust`
+  #[arg(short, long = "query", value_name = "KEY=VALUE", value_parser = parse_pair)]
+  query: Vec<(String, String)>,
+}
+
+#[derive(Debug, Args)]
+pub struct RenderArgs {
+  #[arg(long)]
+  label: Option<String>,
+  #[arg(long)]
+  value: String,
+  #[arg(long)]
+  color: Option<String>,
+  #[command(flatten)]
+  output: Output,
+}
+
+#[derive(Debug, Args)]
+pub struct FetchArgs {
+  /// Route segments, e.g. `github stars vladkens badges`
+  #[arg(required = true, num_args = 1..)]
+  route: Vec<String>,
+  #[command(flatten)]
+  output: Output,
+}
+
+fn parse_pair(s: &str) -> Res<(String, String)> {
+  let (k, v) = s.split_once('=').ok_or_else(|| anyhow!("expected key=value, got {s}"))?;
+  Ok((k.to_string(), v.to_string()))
+}
+
+impl Output {
+  fn format(&self) -> Res<&'static str> {
+    let Some(path) = &self.output else { return Ok("svg") };
+    match path.extension().and_then(|x| x.to_str()).map(|x| x.to_lowercase()).as_deref() {
+      Some("svg") => Ok("svg"),
+      Some("json") => Ok("json"),
+      Some("png") => Ok("png"),
+      Some("webp") => Ok("webp"),
+      _ => Err(anyhow!("unsupported output format: {}", path.display())),
+    }
+  }
+
+  fn query(&self) -> Res<Dict> {
+    let mut qs: Dict = self.query.iter().cloned().collect();
+    qs.insert("format".to_string(), self.format()?.to_string());
+    Ok(qs)
+  }
+
+  fn write(&self, content: &[u8]) -> Res {
+    match &self.output {
+      Some(path) => std::fs::write(path, content).map_err(|e| anyhow!("{}: {e}", path.display())),
+      None => {
+        use std::io::Write;
+        Ok(std::io::stdout().write_all(content)?)
+      }
+    }
+  }
+}
+
+// MARK: Commands
+
+pub fn render(args: RenderArgs) -> Res {
+  let mut qs = args.output.query()?;
+  qs.insert("value".to_string(), args.value);
+  let pairs = [("label", args.label), ("color", args.color)];
+  qs.extend(pairs.into_iter().filter_map(|(k, v)| Some((k.to_string(), v?))));
+
+  let badge = Badge::from_qs(&qs)?;
+  args.output.write(&badge.to_bytes()?)
+}
+
+// Request goes through the same router as in server, so any route can be used
+pub async fn fetch(app: Router, args: FetchArgs) -> Res {
+  let mut url = reqwest::Url::parse("http://localhost")?;
+  url.set_path(&args.route.join("/"));
+  url.query_pairs_mut().extend_pairs(args.output.query()?);
+  let uri = format!("{}?{}", url.path(), url.query().unwrap_or_default());
+
+  let req = Request::get(&uri).body(Body::empty())?;
+  let rep = app.oneshot(req).await?;
+  if let Some(failed) = rep.extensions().get::<BadgeFailed>() {
+    return Err(anyhow!("{uri}: {}", failed.message));
+  }
+
+  if !rep.status().is_success() {
+    return Err(anyhow!("{uri}: {}", rep.status()));
+  }
+
+  let content = axum::body::to_bytes(rep.into_body(), usize::MAX).await?;
+  args.output.write(&content)
+}
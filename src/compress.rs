@@ -0,0 +1,165 @@
+use std::io::Write;
+use std::num::NonZeroUsize;
+use std::sync::{LazyLock, Mutex};
+
+use axum::body::{Body, Bytes};
+use axum::extract::Request;
+use axum::http::{HeaderMap, HeaderValue, StatusCode, header};
+use axum::middleware::Next;
+use axum::response::Response;
+use lru::LruCache;
+use strum::{EnumIter, IntoEnumIterator, IntoStaticStr};
+
+use crate::server::Res;
+
+// Responses smaller than this are sent as is, headers would eat the gain
+const MIN_SIZE: usize = 256;
+
+// MARK: Encoding
+
+// Listed in order of preference, used when client gives same weight to several
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, IntoStaticStr)]
+#[strum(serialize_all = "lowercase")]
+pub enum Encoding {
+  #[strum(serialize = "br")]
+  Brotli,
+  Zstd,
+  Gzip,
+}
+
+impl Encoding {
+  // Picks encoding from `Accept-Encoding`, e.g. `gzip, deflate, br;q=0.9`
+  pub fn negotiate(accept: &str) -> Option<Encoding> {
+    let weight = |enc: Encoding| {
+      let name: &str = enc.into();
+      let mut found = None;
+      for item in accept.split(',') {
+        let mut parts = item.split(';').map(|x| x.trim());
+        let coding = parts.next().unwrap_or_default();
+        let q = parts
+          .find_map(|x| x.strip_prefix("q="))
+          .map_or(Some(1.0), |x| x.parse::<f32>().ok())
+          .unwrap_or(0.0);
+
+        // exact name wins over `*`
+        if coding.eq_ignore_ascii_case(name) || (coding == "*" && found.is_none()) {
+          found = Some(q);
+        }
+      }
+
+      found.unwrap_or(0.0)
+    };
+
+    let mut best: Option<(Encoding, f32)> = None;
+    for enc in Encoding::iter() {
+      let q = weight(enc);
+      if q > 0.0 && best.is_none_or(|(_, x)| q > x) {
+        best = Some((enc, q));
+      }
+    }
+
+    best.map(|(enc, _)| enc)
+  }
+
+  pub fn encode(&self, data: &[u8]) -> Res<Vec<u8>> {
+    let mut out = vec![];
+    match self {
+      Encoding::Brotli => {
+        let params = brotli::enc::BrotliEncoderParams { quality: 9, ..Default::default() };
+        brotli::BrotliCompress(&mut &data[..], &mut out, &params)?;
+      }
+      Encoding::Zstd => out = zstd::encode_all(data, 9)?,
+      Encoding::Gzip => {
+        let mut enc = flate2::write::GzEncoder::new(out, flate2::Compression::best());
+        enc.write_all(data)?;
+        out = enc.finish()?;
+      }
+    }
+
+    Ok(out)
+  }
+}
+
+// MARK: Cache
+
+// Badges have content hash in `ETag`, so hot ones are compressed once per encoding
+type Cache = Mutex<LruCache<(String, Encoding), Bytes>>; // (etag, encoding) -> compressed
+
+static CACHE: LazyLock<Option<Cache>> = LazyLock::new(|| {
+  let size = NonZeroUsize::new(crate::config::get().server.compression_cache)?;
+  Some(Mutex::new(LruCache::new(size)))
+});
+
+fn encode_cached(etag: Option<&str>, enc: Encoding, data: &[u8]) -> Res<Bytes> {
+  let (Some(cache), Some(etag)) = (CACHE.as_ref(), etag) else {
+    return Ok(enc.encode(data)?.into());
+  };
+
+  let key = (etag.to_string(), enc);
+  if let Some(x) = cache.lock().unwrap().get(&key) {
+    return Ok(x.clone());
+  }
+
+  let out = Bytes::from(enc.encode(data)?);
+  cache.lock().unwrap().put(key, out.clone());
+  Ok(out)
+}
+
+// MARK: Middleware
+
+fn compressible(headers: &HeaderMap) -> bool {
+  let mime = headers.get(header::CONTENT_TYPE).and_then(|x| x.to_str().ok()).unwrap_or_default();
+  let mime = mime.split(';').next().unwrap_or_default().trim();
+  mime.starts_with("text/") || ["image/svg+xml", "application/json"].contains(&mime)
+}
+
+// Negotiates gzip, brotli or zstd for SVG, JSON and HTML responses
+pub async fn compress(req: Request, next: Next) -> Response {
+  let accept = req.headers().get(header::ACCEPT_ENCODING).and_then(|x| x.to_str().ok());
+  let enc = accept.and_then(Encoding::negotiate);
+
+  let rep = next.run(req).await;
+  if !crate::config::get().server.compression
+    || !compressible(rep.headers())
+    || rep.headers().contains_key(header::CONTENT_ENCODING)
+    || matches!(rep.status(), StatusCode::NO_CONTENT | StatusCode::NOT_MODIFIED)
+  {
+    return rep;
+  }
+
+  let (mut parts, body) = rep.into_parts();
+  parts.headers.append(header::VARY, HeaderValue::from_static("accept-encoding"));
+
+  let data = match axum::body::to_bytes(body, usize::MAX).await {
+    Ok(x) => x,
+    Err(e) => {
+      tracing::error!("failed to read response body: {:?}", e);
+      return Response::from_parts(parts, Body::empty());
+    }
+  };
+
+  let Some(enc) = enc.filter(|_| data.len() >= MIN_SIZE) else {
+    return Response::from_parts(parts, Body::from(data));
+  };
+
+  let etag = parts.headers.get(header::ETAG).and_then(|x| x.to_str().ok()).map(String::from);
+  let out = match encode_cached(etag.as_deref(), enc, &data) {
+    Ok(x) => x,
+    Err(e) => {
+      tracing::error!("failed to compress response: {:?}", e);
+      return Response::from_parts(parts, Body::from(data));
+    }
+  };
+
+  // same tag for every encoding is only fine for weak comparison (as nginx does)
+  if let Some(etag) = etag.filter(|x| !x.starts_with("W/"))
+    && let Ok(weak) = HeaderValue::from_str(&format!("W/{etag}"))
+  {
+    parts.headers.insert(header::ETAG, weak);
+  }
+
+  let name: &'static str = enc.into();
+  parts.headers.insert(header::CONTENT_ENCODING, HeaderValue::from_static(name));
+  parts.headers.remove(header::CONTENT_LENGTH);
+  Response::from_parts(parts, Body::from(out))
+}
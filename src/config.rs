@@ -0,0 +1,357 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use anyhow::anyhow;
+use badgelib::{BadgeStyle, Color, Defaults};
+use serde::Deserialize;
+
+use crate::apis::INTEGRATIONS;
+use crate::server::Res;
+
+// Config is read from TOML file (see `config.example.toml`), env vars override file values
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+  pub host: String,
+  pub port: u16,
+  pub log_level: String,
+  pub compression: bool,        // gzip, brotli or zstd by `Accept-Encoding`
+  pub compression_cache: usize, // compressed badges kept in memory, 0 to disable
+}
+
+impl Default for ServerConfig {
+  fn default() -> Self {
+    Self {
+      host: "127.0.0.1".to_string(),
+      port: 8080,
+      log_level: "info".to_string(),
+      compression: true,
+      compression_cache: 4096,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheConfig {
+  pub backend: String,
+  pub path: String,
+  pub redis_url: String,
+  pub ttl: u64,
+  pub stale_ttl: u64,
+  pub max_entries: usize,
+  pub timeout: u64,
+  pub refresh_ahead: u64,
+  pub refresh_idle: u64,
+  pub refresh_limit: usize,
+}
+
+impl Default for CacheConfig {
+  fn default() -> Self {
+    Self {
+      backend: "memory".to_string(),
+      path: "badges-cache.redb".to_string(),
+      redis_url: "redis://127.0.0.1:6379".to_string(),
+      ttl: 60,
+      stale_ttl: 86400 * 7,
+      max_entries: 100_000,
+      timeout: 10,
+      refresh_ahead: 0,
+      refresh_idle: 600,
+      refresh_limit: 4,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UpstreamConfig {
+  pub connect_timeout_ms: u64,
+  pub read_timeout_ms: u64,
+  pub retries: u32,
+  pub backoff_ms: u64,
+  pub proxy: Option<String>,
+  pub ca_file: Option<String>,
+  pub base_url: Option<String>, // `https://host/path` -> `{base_url}/host/path`, used by tests
+  pub hosts: HashMap<String, String>, // per host base url, e.g. `"api.github.com" = "http://..."`
+}
+
+impl Default for UpstreamConfig {
+  fn default() -> Self {
+    Self {
+      connect_timeout_ms: 3_000,
+      read_timeout_ms: 5_000,
+      retries: 2,
+      backoff_ms: 200,
+      proxy: None,
+      ca_file: None,
+      base_url: None,
+      hosts: HashMap::new(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BadgeConfig {
+  pub style: String,
+  pub label_color: Option<String>,
+  pub icon_color: Option<String>,
+  pub cache: u32,
+  pub max_scale: f32,
+  pub max_radius: u8,
+  pub icons_dir: Option<String>, // extra `{name}.svg` icons, used as `icon={name}`
+  pub remote_icons: bool,        // allow `icon=https://...`, off as server fetches any url
+}
+
+impl Default for BadgeConfig {
+  fn default() -> Self {
+    let x = Defaults::default();
+    Self {
+      style: "flat".to_string(),
+      label_color: None,
+      icon_color: None,
+      cache: x.cache,
+      max_scale: x.max_scale,
+      max_radius: x.max_radius,
+      icons_dir: None,
+      remote_icons: false,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct IntegrationConfig {
+  pub enabled: bool,
+  pub ttl: Option<u64>,
+}
+
+impl Default for IntegrationConfig {
+  fn default() -> Self {
+    Self { enabled: true, ttl: None }
+  }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GithubConfig {
+  pub tokens: Vec<String>,
+  pub tokens_file: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GitlabConfig {
+  pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+  pub server: ServerConfig,
+  pub cache: CacheConfig,
+  pub upstream: UpstreamConfig,
+  pub badge: BadgeConfig,
+  pub github: GithubConfig,
+  pub gitlab: GitlabConfig,
+  pub integrations: HashMap<String, IntegrationConfig>,
+}
+
+// MARK: Loading
+
+struct Env(Vec<String>); // collected parse errors
+
+impl Env {
+  fn get<T: std::str::FromStr>(&mut self, name: &str) -> Option<T> {
+    let value = std::env::var(name).ok()?;
+    match value.parse() {
+      Ok(x) => Some(x),
+      Err(_) => {
+        self.0.push(format!("{name}: invalid value {value:?}"));
+        None
+      }
+    }
+  }
+
+  fn set<T: std::str::FromStr>(&mut self, name: &str, target: &mut T) {
+    if let Some(x) = self.get(name) {
+      *target = x;
+    }
+  }
+}
+
+impl Config {
+  fn apply_env(&mut self) -> Vec<String> {
+    let mut env = Env(vec![]);
+
+    env.set("HOST", &mut self.server.host);
+    env.set("PORT", &mut self.server.port);
+    env.set("LOG_LEVEL", &mut self.server.log_level);
+    env.set("COMPRESSION", &mut self.server.compression);
+    env.set("COMPRESSION_CACHE", &mut self.server.compression_cache);
+
+    let c = &mut self.cache;
+    env.set("CACHE_BACKEND", &mut c.backend);
+    env.set("CACHE_PATH", &mut c.path);
+    env.set("REDIS_URL", &mut c.redis_url);
+    env.set("CACHE_TTL", &mut c.ttl);
+    env.set("CACHE_STALE_TTL", &mut c.stale_ttl);
+    env.set("CACHE_MAX_ENTRIES", &mut c.max_entries);
+    env.set("FETCH_TIMEOUT", &mut c.timeout);
+    env.set("CACHE_REFRESH_AHEAD", &mut c.refresh_ahead);
+    env.set("CACHE_REFRESH_IDLE", &mut c.refresh_idle);
+    env.set("CACHE_REFRESH_LIMIT", &mut c.refresh_limit);
+
+    let u = &mut self.upstream;
+    env.set("UPSTREAM_CONNECT_TIMEOUT_MS", &mut u.connect_timeout_ms);
+    env.set("UPSTREAM_READ_TIMEOUT_MS", &mut u.read_timeout_ms);
+    env.set("UPSTREAM_RETRIES", &mut u.retries);
+    env.set("UPSTREAM_BACKOFF_MS", &mut u.backoff_ms);
+    u.proxy = env.get("UPSTREAM_PROXY").or(u.proxy.take());
+    u.ca_file = env.get("UPSTREAM_CA_FILE").or(u.ca_file.take());
+    u.base_url = env.get("UPSTREAM_BASE_URL").or(u.base_url.take());
+
+    let tokens: Option<String> = env.get("GITHUB_TOKENS").or_else(|| env.get("GITHUB_TOKEN"));
+    if let Some(tokens) = tokens {
+      self.github.tokens = vec![tokens]; // split by token pool
+    }
+    self.github.tokens_file = env.get("GITHUB_TOKENS_FILE").or(self.github.tokens_file.take());
+    self.gitlab.url = env.get("GITLAB_URL").or(self.gitlab.url.take());
+    self.badge.icons_dir = env.get("ICONS_DIR").or(self.badge.icons_dir.take());
+    env.set("REMOTE_ICONS", &mut self.badge.remote_icons);
+
+    // `CACHE_TTL_NPM=300` -> `[integrations.npm] ttl = 300`
+    for name in INTEGRATIONS {
+      let key = format!("CACHE_TTL_{}", name.to_uppercase());
+      if let Some(ttl) = env.get(&key) {
+        self.integrations.entry(name.to_string()).or_default().ttl = Some(ttl);
+      }
+    }
+
+    env.0
+  }
+
+  fn validate(&self) -> Vec<String> {
+    let mut errors = vec![];
+
+    if tracing_subscriber::EnvFilter::try_new(&self.server.log_level).is_err() {
+      errors.push(format!("server.log_level: invalid value {:?}", self.server.log_level));
+    }
+
+    if !["memory", "disk", "redis"].contains(&self.cache.backend.as_str()) {
+      errors.push(format!(
+        "cache.backend: expected memory, disk or redis, got {:?}",
+        self.cache.backend
+      ));
+    }
+
+    if self.cache.timeout == 0 {
+      errors.push("cache.timeout: should be greater than 0".to_string());
+    }
+
+    if BadgeStyle::parse(&self.badge.style).is_none() {
+      errors.push(format!("badge.style: unknown style {:?}", self.badge.style));
+    }
+
+    let b = &self.badge;
+    let colors = [("badge.label_color", &b.label_color), ("badge.icon_color", &b.icon_color)];
+    for (name, color) in colors {
+      if let Some(color) = color
+        && Color::from_str(color).is_err()
+      {
+        errors.push(format!("{name}: invalid color {color:?}"));
+      }
+    }
+
+    let u = &self.upstream;
+    let urls = u.base_url.iter().map(|x| ("upstream.base_url".to_string(), x));
+    let urls = urls.chain(u.hosts.iter().map(|(k, v)| (format!("upstream.hosts.{k}"), v)));
+    for (name, url) in urls {
+      if reqwest::Url::parse(url).is_err() {
+        errors.push(format!("{name}: invalid url {url:?}"));
+      }
+    }
+
+    if let Some(path) = &self.github.tokens_file
+      && std::fs::metadata(path).is_err()
+    {
+      errors.push(format!("github.tokens_file: can't read {path:?}"));
+    }
+
+    if let Some(dir) = &self.badge.icons_dir
+      && !std::path::Path::new(dir).is_dir()
+    {
+      errors.push(format!("badge.icons_dir: not a directory {dir:?}"));
+    }
+
+    if self.badge.cache < 300 {
+      errors.push("badge.cache: should be at least 300".to_string());
+    }
+
+    if self.badge.max_scale < 1.0 {
+      errors.push("badge.max_scale: should be at least 1".to_string());
+    }
+
+    for name in self.integrations.keys() {
+      if !INTEGRATIONS.contains(&name.as_str()) {
+        errors.push(format!("integrations.{name}: unknown integration"));
+      }
+    }
+
+    errors
+  }
+
+  pub fn load(path: Option<&str>) -> Res<Config> {
+    let mut config = match path {
+      Some(path) => {
+        let data =
+          std::fs::read_to_string(path).map_err(|e| anyhow!("failed to read {path}: {e}"))?;
+        toml::from_str::<Config>(&data).map_err(|e| anyhow!("invalid config {path}: {e}"))?
+      }
+      None => Config::default(),
+    };
+
+    let mut errors = config.apply_env();
+    errors.extend(config.validate());
+    if !errors.is_empty() {
+      return Err(anyhow!("invalid config:\n  {}", errors.join("\n  ")));
+    }
+
+    Ok(config)
+  }
+
+  pub fn badge_defaults(&self) -> Defaults {
+    let b = &self.badge;
+    let label_color = b.label_color.as_ref().and_then(|x| Color::from_str(x).ok());
+    Defaults {
+      style: BadgeStyle::parse(&b.style).unwrap_or_default(),
+      label_color: label_color.unwrap_or(Color::DefaultLabel),
+      icon_color: b.icon_color.as_ref().and_then(|x| Color::from_str(x).ok()),
+      cache: b.cache,
+      max_scale: b.max_scale,
+      max_radius: b.max_radius,
+    }
+  }
+
+  pub fn enabled(&self, integration: &str) -> bool {
+    self.integrations.get(integration).is_none_or(|x| x.enabled)
+  }
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+// Path from `--config` arg or `BADGES_CONFIG` env
+pub fn init(path: Option<&str>) -> Res<&'static Config> {
+  let config = Config::load(path)?;
+  Defaults::set(config.badge_defaults());
+  if let Some(dir) = &config.badge.icons_dir {
+    badgelib::set_custom_icons(crate::icons::load_dir(dir)?);
+  }
+  Ok(CONFIG.get_or_init(|| config))
+}
+
+pub fn get() -> &'static Config {
+  CONFIG.get_or_init(|| Config::load(None).unwrap_or_default())
+}
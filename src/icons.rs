@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use axum::extract::{Query, Request};
+use axum::middleware::Next;
+use axum::response::Response;
+use badgelib::{MAX_ICON_SIZE, sanitize_icon};
+
+use crate::apis::{get_client, read_limited};
+use crate::cache::cached;
+use crate::server::{Dict, ErrorKind, Res};
+
+// MARK: Directory
+
+// `{dir}/logo.svg` is available as `icon=logo`, loaded once on startup
+pub fn load_dir(dir: &str) -> Res<HashMap<String, String>> {
+  let entries = std::fs::read_dir(dir).map_err(|e| anyhow!("failed to read {dir}: {e}"))?;
+
+  let mut icons = HashMap::new();
+  for entry in entries {
+    let path = entry?.path();
+    if path.extension().is_none_or(|x| x != "svg") {
+      continue;
+    }
+
+    let name = path.file_stem().unwrap_or_default().to_string_lossy().to_lowercase();
+    let data = std::fs::read(&path)?;
+    let icon = sanitize_icon(&data).map_err(|e| anyhow!("invalid icon {}: {e}", path.display()))?;
+    icons.insert(name, icon);
+  }
+
+  tracing::info!("loaded {} custom icons from {}", icons.len(), dir);
+  Ok(icons)
+}
+
+// MARK: Remote
+
+tokio::task_local! {
+  static RESOLVED: HashMap<String, String>;
+}
+
+// Hook for badgelib, returns icon fetched for current request
+pub fn resolved(url: &str) -> Option<String> {
+  RESOLVED.try_with(|x| x.get(url).cloned()).ok().flatten()
+}
+
+async fn fetch_icon(url: String) -> Res<String> {
+  cached("icon:fetch", url, |url| async move {
+    let rep = get_client().get(&url).send().await?.error_for_status()?;
+    let data = read_limited(rep, MAX_ICON_SIZE).await?;
+    sanitize_icon(&data).map_err(|e| ErrorKind::InvalidParams.msg(e))
+  })
+  .await
+}
+
+// `icon=https://...` is fetched and sanitized before badge is rendered, on failure badge is
+// rendered without icon
+pub async fn resolve_remote(req: Request, next: Next) -> Response {
+  let qs = Query::<Dict>::try_from_uri(req.uri()).map(|x| x.0).unwrap_or_default();
+  let urls = ["icon", "logo"].iter().filter_map(|k| qs.get(*k));
+  let urls = urls.filter(|x| x.starts_with("https://")).cloned().collect::<Vec<_>>();
+
+  let mut icons = HashMap::new();
+  if crate::config::get().badge.remote_icons {
+    for url in urls {
+      match fetch_icon(url.clone()).await {
+        Ok(icon) => _ = icons.insert(url, icon),
+        Err(e) => tracing::warn!("failed to fetch icon {}: {}", url, e),
+      }
+    }
+  }
+
+  RESOLVED.scope(icons, next.run(req)).await
+}
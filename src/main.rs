@@ -1,8 +1,18 @@
 use axum::{Router, routing::get};
+use clap::Parser;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
 use tracing_subscriber::layer::SubscriberExt;
 
+use crate::cli::Command;
+
 mod apis;
-mod badgelib;
+mod cache;
+mod cli;
+mod compress;
+mod config;
+mod debug_items;
+mod icons;
+mod metrics;
 mod pages;
 mod server;
 
@@ -24,23 +34,52 @@ macro_rules! redirect {
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-  let env_filter = tracing_subscriber::EnvFilter::builder()
-    .with_default_directive(tracing::Level::INFO.into())
-    .from_env_lossy();
+  let cli = cli::Cli::parse();
+  let config = config::init(cli.config.as_deref())?;
+  badgelib::set_stale_hook(cache::stale_since);
+  badgelib::set_remote_icon_hook(icons::resolved);
+  let command = cli.command.unwrap_or(Command::Serve);
+
+  // `RUST_LOG` takes precedence over configured log level
+  let env_filter = tracing_subscriber::EnvFilter::try_from_default_env()
+    .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new(&config.server.log_level));
+
+  // stdout is reserved for badge output in cli commands
+  let writer = match command {
+    Command::Serve => BoxMakeWriter::new(std::io::stdout),
+    _ => BoxMakeWriter::new(std::io::stderr),
+  };
 
   let logfmt = tracing_logfmt::builder()
     .with_target(false)
     .with_span_name(false)
     .with_span_path(false)
-    .layer();
+    .layer()
+    .with_writer(writer);
 
   tracing::dispatcher::set_global_default(tracing::Dispatch::new(
     tracing_subscriber::Registry::default().with(env_filter).with(logfmt),
   ))?;
 
-  let brand = format!("{} v{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
-  tracing::info!("{}", brand);
+  match command {
+    Command::Serve => {
+      let brand = format!("{} v{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+      tracing::info!("{}", brand);
 
+      apis::init_client()?;
+      cache::init().await?;
+      server::run_server(server::router(app())).await
+    }
+    Command::Render(args) => Ok(cli::render(args)?),
+    Command::Fetch(args) => {
+      apis::init_client()?;
+      cache::init().await?;
+      Ok(cli::fetch(server::router(app()), args).await?)
+    }
+  }
+}
+
+fn app() -> Router {
   let badges = Router::new()
     .route("/pypi/{kind}/{name}", get(apis::pypi::handler))
     .route("/npm/{kind}/{*name}", get(apis::npm::handler)) // name can be scoped
@@ -64,15 +103,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     .route("/jetbrains/{kind}/{name}", get(apis::jetbrains::handler))
     .route("/github/{kind}/{user}/{repo}", get(apis::github::handler))
     .route("/github/workflow/{user}/{repo}/{name}", get(apis::github::workflow_handler))
+    .route("/gitlab/{kind}/{*name}", get(apis::gitlab::handler)) // name can include subgroups
     .route("/codecov/c/{service}/{user}/{repo}", get(apis::codecov::handler))
     .route("/codecov/c/{service}/{user}/{repo}/{branch}", get(apis::codecov::handler))
     .route("/docker/{kind}/{user}/{repo}", get(apis::docker::handler))
     .route("/docker/{kind}/{user}/{repo}/{tag}", get(apis::docker::handler))
     .route("/readthedocs/{name}", get(apis::readthedocs::handler))
     .route("/discord/{name}", get(apis::discord::handler))
+    .route("/endpoint", get(apis::endpoint::handler))
+    .route("/dynamic/{kind}", get(apis::dynamic::handler))
     .route("/badge", get(apis::fixed::handler1))
     .route("/badge/{config}", get(apis::fixed::handler2))
-    .route("/badge/{label}/{value}/{color}", get(apis::fixed::handler3));
+    .route("/badge/{config}/{*rest}", get(apis::fixed::handler3));
 
   let compatibility = Router::new()
     .route("/pypi/pyversions/{*rest}", redirect!("/pypi/python/{*rest}"))
@@ -83,13 +125,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     .route("/github/languages/top/{*rest}", redirect!("/github/lang-top/{*rest}"))
     .route("/github/languages/count/{*rest}", redirect!("/github/lang-count/{*rest}"))
     .route("/github/languages/code-size/{*rest}", redirect!("/github/lang-size/{*rest}"))
-    .route("/github/v/release/{*rest}", redirect!("/github/release/{*rest}"));
+    .route("/github/v/release/{*rest}", redirect!("/github/release/{*rest}"))
+    .route("/gitlab/v/release/{*rest}", redirect!("/gitlab/release/{*rest}"))
+    .route("/gitlab/v/tag/{*rest}", redirect!("/gitlab/tag/{*rest}"))
+    .route("/gitlab/issues/open/{*rest}", redirect!("/gitlab/issues/{*rest}"))
+    .route("/gitlab/merge-requests/open/{*rest}", redirect!("/gitlab/mrs/{*rest}"));
 
-  let app = axum::Router::new()
+  Router::new()
     .merge(compatibility)
     .merge(badges)
     .route("/", get(pages::index))
-    .route("/debug", get(pages::debug));
-
-  server::run_server(app).await
+    .route("/debug", get(pages::debug))
 }
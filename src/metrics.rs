@@ -0,0 +1,133 @@
+use std::sync::LazyLock;
+use std::time::Instant;
+
+use axum::extract::Request;
+use axum::http::{Extensions, StatusCode, header};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use prometheus::{
+  Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, Opts, Registry, TextEncoder,
+};
+
+pub struct Metrics {
+  registry: Registry,
+  pub requests: IntCounterVec, // route, integration, status
+  pub duration: HistogramVec,  // route, integration
+  pub in_flight: IntGauge,
+  pub cache: IntCounterVec, // event: hit, miss, coalesced, stale, refreshed
+  pub upstream: HistogramVec, // host, status
+  pub errors: IntCounterVec, // cause
+}
+
+fn counter(registry: &Registry, name: &str, help: &str, labels: &[&str]) -> IntCounterVec {
+  let metric = IntCounterVec::new(Opts::new(name, help), labels).unwrap();
+  registry.register(Box::new(metric.clone())).unwrap();
+  metric
+}
+
+fn histogram(registry: &Registry, name: &str, help: &str, labels: &[&str]) -> HistogramVec {
+  let metric = HistogramVec::new(HistogramOpts::new(name, help), labels).unwrap();
+  registry.register(Box::new(metric.clone())).unwrap();
+  metric
+}
+
+pub static METRICS: LazyLock<Metrics> = LazyLock::new(|| {
+  let registry = Registry::new_custom(Some("badges".to_string()), None).unwrap();
+
+  let in_flight = IntGauge::new("http_requests_in_flight", "Requests being processed").unwrap();
+  registry.register(Box::new(in_flight.clone())).unwrap();
+
+  Metrics {
+    requests: counter(
+      &registry,
+      "http_requests_total",
+      "Handled requests",
+      &["route", "integration", "status"],
+    ),
+    duration: histogram(
+      &registry,
+      "http_request_duration_seconds",
+      "Request latency",
+      &["route", "integration"],
+    ),
+    in_flight,
+    cache: counter(&registry, "cache_events_total", "Upstream cache lookups", &["event"]),
+    upstream: histogram(
+      &registry,
+      "upstream_request_duration_seconds",
+      "Upstream request latency",
+      &["host", "status"],
+    ),
+    errors: counter(&registry, "error_badges_total", "Rendered error badges", &["cause"]),
+    registry,
+  }
+});
+
+// MARK: Server
+
+// Decrements on drop, so requests cancelled by client disconnect are not counted forever
+struct InFlight;
+
+impl InFlight {
+  fn start() -> Self {
+    METRICS.in_flight.inc();
+    InFlight
+  }
+}
+
+impl Drop for InFlight {
+  fn drop(&mut self) {
+    METRICS.in_flight.dec();
+  }
+}
+
+pub async fn track(req: Request, next: Next) -> Response {
+  let (route, integration) = crate::server::route_of(&req);
+
+  let m = &*METRICS;
+  let in_flight = InFlight::start();
+  let started = Instant::now();
+  let rep = next.run(req).await;
+  drop(in_flight);
+
+  let status = rep.status().as_u16().to_string();
+  m.requests.with_label_values(&[&route, &integration, &status]).inc();
+  m.duration.with_label_values(&[&route, &integration]).observe(started.elapsed().as_secs_f64());
+  rep
+}
+
+pub async fn handler() -> impl IntoResponse {
+  let mut buf = Vec::new();
+  let encoder = TextEncoder::new();
+  match encoder.encode(&METRICS.registry.gather(), &mut buf) {
+    Ok(_) => (StatusCode::OK, [(header::CONTENT_TYPE, encoder.format_type())], buf).into_response(),
+    Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+  }
+}
+
+// MARK: Upstream
+
+pub struct Upstream;
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for Upstream {
+  async fn handle(
+    &self,
+    req: reqwest::Request,
+    extensions: &mut Extensions,
+    next: reqwest_middleware::Next<'_>,
+  ) -> reqwest_middleware::Result<reqwest::Response> {
+    let host = req.url().host_str().unwrap_or_default().to_string();
+
+    let started = Instant::now();
+    let rep = next.run(req, extensions).await;
+    let status = match &rep {
+      Ok(x) => x.status().as_u16().to_string(),
+      Err(_) => "error".to_string(),
+    };
+
+    let elapsed = started.elapsed().as_secs_f64();
+    METRICS.upstream.with_label_values(&[&host, &status]).observe(elapsed);
+    rep
+  }
+}
@@ -2,18 +2,18 @@ use std::collections::HashMap;
 
 use axum::{
   Json, ServiceExt,
-  extract::Request,
-  http::{StatusCode, Uri, header},
+  extract::{FromRequestParts, MatchedPath, Query, Request},
+  http::{StatusCode, Uri, header, request::Parts},
+  middleware::Next,
   response::{IntoResponse, Response},
   routing::get,
 };
+use badgelib::{Badge, Color};
 use rust_embed::Embed;
 use tokio::net::TcpListener;
 use tower_layer::Layer;
 use tracing::Level;
 
-use crate::badgelib::{Badge, Color};
-
 pub type Res<T = ()> = anyhow::Result<T>;
 pub type Dict = HashMap<String, String>;
 
@@ -34,11 +34,141 @@ impl IntoResponse for AppError {
   }
 }
 
+// MARK: RateLimitError
+
+#[derive(Debug)]
+pub struct RateLimitError {
+  pub reset: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl std::fmt::Display for RateLimitError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self.reset {
+      Some(reset) => write!(f, "rate limited until {}", reset),
+      None => write!(f, "rate limited"),
+    }
+  }
+}
+
+impl std::error::Error for RateLimitError {}
+
+// MARK: ErrorKind
+
+// Cause of error badge, fetchers can return it directly: `Err(ErrorKind::NotFound)?`
+#[derive(Debug, Clone, Copy, PartialEq, strum::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum ErrorKind {
+  NotFound,
+  RateLimited,
+  UpstreamTimeout,
+  InvalidParams,
+  UnsupportedKind,
+  UpstreamParse,
+  UpstreamStatus, // other non-2xx status from upstream
+  UpstreamError,  // connection error
+  Unknown,
+}
+
+impl std::fmt::Display for ErrorKind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.text())
+  }
+}
+
+impl std::error::Error for ErrorKind {}
+
+impl ErrorKind {
+  // Error with details for logs, e.g. `ErrorKind::InvalidParams.msg("url is required")`
+  pub fn msg<M: std::fmt::Display + Send + Sync + 'static>(self, msg: M) -> anyhow::Error {
+    anyhow::Error::new(self).context(msg)
+  }
+
+  pub fn of(e: &anyhow::Error) -> ErrorKind {
+    let e = crate::cache::SharedError::unwrap(e);
+    if let Some(kind) = e.chain().find_map(|x| x.downcast_ref::<ErrorKind>()) {
+      return *kind;
+    }
+
+    if e.chain().any(|x| x.is::<RateLimitError>()) {
+      return ErrorKind::RateLimited;
+    }
+
+    // connection errors are wrapped by client middleware
+    let reqwest_err = e.downcast_ref::<reqwest::Error>().or_else(|| match e
+      .downcast_ref::<reqwest_middleware::Error>()
+    {
+      Some(reqwest_middleware::Error::Reqwest(e)) => Some(e),
+      _ => None,
+    });
+
+    if let Some(e) = reqwest_err {
+      return match e.status().map(|x| x.as_u16()) {
+        Some(404 | 410) => ErrorKind::NotFound,
+        Some(429) => ErrorKind::RateLimited,
+        Some(_) => ErrorKind::UpstreamStatus,
+        None if e.is_timeout() => ErrorKind::UpstreamTimeout,
+        None if e.is_decode() => ErrorKind::UpstreamParse,
+        None => ErrorKind::UpstreamError,
+      };
+    }
+
+    let parse = e.chain().any(|x| {
+      x.is::<serde_json::Error>()
+        || x.is::<std::num::ParseIntError>()
+        || x.is::<std::num::ParseFloatError>()
+    });
+
+    if parse { ErrorKind::UpstreamParse } else { ErrorKind::Unknown }
+  }
+
+  // Name in JSON output and metrics, e.g. `not_found`
+  pub fn name(&self) -> &'static str {
+    self.into()
+  }
+
+  fn text(&self) -> &'static str {
+    match self {
+      ErrorKind::NotFound => "package not found",
+      ErrorKind::RateLimited => "rate limited",
+      ErrorKind::UpstreamTimeout => "timeout",
+      ErrorKind::InvalidParams => "invalid",
+      ErrorKind::UnsupportedKind => "unsupported",
+      ErrorKind::UpstreamParse => "invalid response",
+      ErrorKind::UpstreamStatus | ErrorKind::UpstreamError => "api error",
+      ErrorKind::Unknown => "unknown",
+    }
+  }
+
+  // Used only with `strict=1`, badge is returned with 200 by default
+  fn status(&self) -> StatusCode {
+    match self {
+      ErrorKind::NotFound => StatusCode::NOT_FOUND,
+      ErrorKind::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+      ErrorKind::UpstreamTimeout => StatusCode::GATEWAY_TIMEOUT,
+      ErrorKind::InvalidParams | ErrorKind::UnsupportedKind => StatusCode::BAD_REQUEST,
+      ErrorKind::UpstreamParse | ErrorKind::UpstreamStatus | ErrorKind::UpstreamError => {
+        StatusCode::BAD_GATEWAY
+      }
+      ErrorKind::Unknown => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+  }
+}
+
 // MARK: BadgeError
 
 pub struct BadgeError(anyhow::Error);
 pub type BadgeRep = std::result::Result<Badge, BadgeError>;
 
+// Kept in response extensions to render error badge with request options in `render_error`,
+// also used by `badges fetch` to fail on error badges
+#[derive(Debug, Clone)]
+pub struct BadgeFailed {
+  pub kind: ErrorKind,
+  pub message: String,
+  value: String,
+  cache: Option<u32>,
+}
+
 impl<E: Into<anyhow::Error>> From<E> for BadgeError {
   fn from(err: E) -> Self {
     Self(err.into())
@@ -47,16 +177,174 @@ impl<E: Into<anyhow::Error>> From<E> for BadgeError {
 
 impl IntoResponse for BadgeError {
   fn into_response(self) -> Response {
+    let failed = self.to_failed();
+    let mut rep = failed.to_badge(&Dict::new()).into_response();
+    rep.extensions_mut().insert(failed);
+    rep
+  }
+}
+
+impl BadgeError {
+  fn to_failed(&self) -> BadgeFailed {
     tracing::error!("error: {:?}", self.0);
 
-    let e = self.0;
-    if e.downcast_ref::<reqwest::Error>().is_some() {
-      let e = e.downcast_ref::<reqwest::Error>().unwrap();
-      let value = e.status().map(|s| s.to_string()).unwrap_or("api error".into());
-      return Badge::new("error", &value, Color::Red).into_response();
+    let kind = ErrorKind::of(&self.0);
+    crate::metrics::METRICS.errors.with_label_values(&[kind.name()]).inc();
+
+    let e = crate::cache::SharedError::unwrap(&self.0);
+    let status = e.chain().find_map(|x| match x.downcast_ref::<reqwest_middleware::Error>() {
+      Some(reqwest_middleware::Error::Reqwest(e)) => e.status(),
+      _ => x.downcast_ref::<reqwest::Error>().and_then(|e| e.status()),
+    });
+
+    let value = match (kind, status) {
+      (ErrorKind::UpstreamStatus, Some(status)) => status.to_string(),
+      _ => kind.text().to_string(),
+    };
+
+    // do not cache for long, badge should recover after limit reset
+    let reset = e.chain().find_map(|x| x.downcast_ref::<RateLimitError>()).map(|x| x.reset);
+    let cache = match (kind, reset) {
+      (ErrorKind::RateLimited, Some(reset)) => {
+        Some(reset.map(|x| (x - chrono::Utc::now()).num_seconds().max(60) as u32).unwrap_or(300))
+      }
+      (ErrorKind::RateLimited, None) => Some(300),
+      _ => None,
+    };
+
+    BadgeFailed { kind, message: format!("{:#}", self.0), value, cache }
+  }
+}
+
+impl BadgeFailed {
+  // Error badge keeps style, format and label from request, but not colors
+  fn to_badge(&self, qs: &Dict) -> Badge {
+    let color = if self.kind == ErrorKind::RateLimited { Color::Orange } else { Color::Red };
+    let mut badge = Badge::from_qs(qs).unwrap_or_else(|_| Badge::new("error", "", Color::Red));
+    badge.llabel = badge.llabel.or(Some("error".to_string()));
+    badge.rlabel = self.value.clone();
+    badge.rcolor = color;
+    badge.segments = vec![];
+    badge.cache = self.cache.unwrap_or(badge.cache);
+    badge.error = Some(self.kind.name().to_string());
+    badge
+  }
+}
+
+// With `strict=1` error badges are returned with real status code, e.g. for uptime monitoring
+async fn render_error(req: Request, next: Next) -> Response {
+  let qs = Query::<Dict>::try_from_uri(req.uri()).map(|x| x.0).unwrap_or_default();
+  let rep = next.run(req).await;
+  let Some(failed) = rep.extensions().get::<BadgeFailed>().cloned() else {
+    return rep;
+  };
+
+  let strict = qs.get("strict").is_some_and(|x| x == "1" || x == "true");
+  let mut rep = failed.to_badge(&qs).into_response();
+  if strict {
+    *rep.status_mut() = failed.kind.status();
+  }
+
+  rep.extensions_mut().insert(failed);
+  rep
+}
+
+// MARK: Conditional requests
+
+// Camo and CDNs revalidate badges often, so unchanged ones are answered without body
+async fn not_modified(req: Request, next: Next) -> Response {
+  let (if_none_match, if_modified_since) = {
+    let get = |name| req.headers().get(name).and_then(|x| x.to_str().ok()).map(|x| x.to_string());
+    (get(header::IF_NONE_MATCH), get(header::IF_MODIFIED_SINCE))
+  };
+
+  let rep = next.run(req).await;
+  if rep.status() != StatusCode::OK {
+    return rep;
+  }
+
+  let etag = rep.headers().get(header::ETAG).and_then(|x| x.to_str().ok());
+  let etag = etag.map(|x| x.trim_start_matches("W/"));
+  let last_modified = rep.headers().get(header::LAST_MODIFIED).and_then(|x| x.to_str().ok());
+  let date = |x: &str| chrono::DateTime::parse_from_rfc2822(x).ok();
+
+  // `If-Modified-Since` is ignored when `If-None-Match` is sent (RFC 9110, 13.1.3)
+  let unchanged = match (if_none_match, if_modified_since) {
+    (Some(tags), _) => etag.is_some_and(|etag| {
+      tags.split(',').map(|x| x.trim().trim_start_matches("W/")).any(|x| x == etag || x == "*")
+    }),
+    (None, Some(since)) => match (last_modified.and_then(date), date(&since)) {
+      (Some(modified), Some(since)) => modified <= since,
+      _ => false,
+    },
+    (None, None) => false,
+  };
+
+  if !unchanged {
+    return rep;
+  }
+
+  let mut not_modified = StatusCode::NOT_MODIFIED.into_response();
+  for name in [header::ETAG, header::CACHE_CONTROL, header::LAST_MODIFIED, header::VARY] {
+    if let Some(value) = rep.headers().get(&name) {
+      not_modified.headers_mut().insert(name, value.clone());
     }
+  }
+
+  not_modified
+}
 
-    Badge::new("error", "unknown", Color::Red).into_response()
+// MARK: Brand colors
+
+const COLOR_KEYS: &[&str] = &["color", "labelColor", "lcolor", "rcolor"];
+
+// `color=brand` without `icon` is brand color of integration, e.g. npm red for `/npm/v/react`
+async fn brand_colors(mut req: Request, next: Next) -> Response {
+  let (_, integration) = route_of(&req);
+  let brand = crate::apis::BRANDS.iter().find(|(k, _)| *k == integration);
+  let brand = brand.and_then(|(_, icon)| badgelib::brand_color(icon));
+
+  let qs = Query::<Vec<(String, String)>>::try_from_uri(req.uri()).map(|x| x.0);
+  let qs = qs.unwrap_or_default();
+  let has_icon = qs.iter().any(|(k, _)| k == "icon" || k == "logo");
+  let is_brand = |k: &str, v: &str| COLOR_KEYS.contains(&k) && v.eq_ignore_ascii_case("brand");
+  let has_brand = qs.iter().any(|(k, v)| is_brand(k, v));
+
+  if let Some(brand) = brand
+    && has_brand
+    && !has_icon
+  {
+    let pairs = qs.into_iter().map(|(k, v)| match is_brand(&k, &v) {
+      true => (k, brand.to_hex()),
+      false => (k, v),
+    });
+
+    let mut url = reqwest::Url::parse("http://localhost").unwrap();
+    url.query_pairs_mut().extend_pairs(pairs);
+    let uri = format!("{}?{}", req.uri().path(), url.query().unwrap_or_default());
+    *req.uri_mut() = uri.parse().unwrap_or_else(|_| req.uri().clone());
+  }
+
+  next.run(req).await
+}
+
+// MARK: Path
+
+// Same as axum `Path`, but unknown badge kind in route is rendered as error badge
+pub struct Path<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for Path<T>
+where
+  T: serde::de::DeserializeOwned + Send,
+  S: Send + Sync,
+{
+  type Rejection = BadgeError;
+
+  async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+    match axum::extract::Path::<T>::from_request_parts(parts, state).await {
+      Ok(axum::extract::Path(x)) => Ok(Path(x)),
+      Err(e) => Err(ErrorKind::UnsupportedKind.msg(e.body_text()).into()),
+    }
   }
 }
 
@@ -84,7 +372,8 @@ async fn shutdown_signal() {
 }
 
 async fn health() -> impl IntoResponse {
-  let msg = serde_json::json!({ "status": "ok", "ver": env!("CARGO_PKG_VERSION") });
+  let cache = crate::cache::stats();
+  let msg = serde_json::json!({ "status": "ok", "ver": env!("CARGO_PKG_VERSION"), "cache": cache });
   (StatusCode::OK, axum::response::Json(msg))
 }
 
@@ -93,6 +382,23 @@ async fn not_found() -> impl IntoResponse {
   (StatusCode::NOT_FOUND, Json(msg))
 }
 
+// Integration is the first segment of route: `/npm/{kind}/{*name}` -> `npm`
+pub fn route_of(req: &Request) -> (String, String) {
+  let route = req.extensions().get::<MatchedPath>().map(|x| x.as_str().to_string());
+  let route = route.unwrap_or("unmatched".to_string());
+  let integration = route.trim_start_matches('/').split('/').next().unwrap_or_default().to_string();
+  (route, integration)
+}
+
+// Integrations can be turned off with `[integrations.<name>] enabled = false`
+async fn check_enabled(req: Request, next: Next) -> Response {
+  let (_, integration) = route_of(&req);
+  match crate::config::get().enabled(&integration) {
+    true => next.run(req).await,
+    false => not_found().await.into_response(),
+  }
+}
+
 async fn favicon() -> impl IntoResponse {
   let bytes = include_bytes!("../assets/favicon.ico");
   (StatusCode::OK, [(header::CONTENT_TYPE, "image/x-icon")], bytes)
@@ -124,9 +430,9 @@ fn rewrite_request_uri<B>(mut req: Request<B>) -> Request<B> {
   let mut path = if path == "/" { path } else { path.trim_end_matches('/') };
   let mut qs = req.uri().query().unwrap_or_default().to_string();
 
-  let has_ext = path.ends_with(".svg") || path.ends_with(".json");
+  let has_ext = [".svg", ".json", ".png", ".webp"].iter().any(|x| path.ends_with(x));
   if !path.starts_with("/assets/") && has_ext {
-    let ext = path.split('.').last().unwrap();
+    let ext = path.split('.').next_back().unwrap();
     path = path.trim_end_matches(&format!(".{}", ext));
     qs = if qs.is_empty() { format!("format={}", ext) } else { format!("{}&format={}", qs, ext) };
   }
@@ -136,18 +442,30 @@ fn rewrite_request_uri<B>(mut req: Request<B>) -> Request<B> {
   req
 }
 
-pub async fn run_server(app: axum::Router) -> Result<(), Box<dyn std::error::Error>> {
-  let app = app
+// Badge routes with all middlewares and service routes, shared by `serve` and `fetch` commands
+pub fn router(app: axum::Router) -> axum::Router {
+  app
+    .layer(axum::middleware::from_fn(crate::cache::track_request))
+    .layer(axum::middleware::from_fn(render_error))
+    .layer(axum::middleware::from_fn(crate::icons::resolve_remote))
+    .layer(axum::middleware::from_fn(brand_colors))
+    .layer(axum::middleware::from_fn(check_enabled))
+    .layer(axum::middleware::from_fn(crate::compress::compress))
+    .layer(axum::middleware::from_fn(not_modified)) // after compress, so 304 has same (weak) etag
+    .layer(axum::middleware::from_fn(crate::metrics::track))
     .layer(
       tower_http::trace::TraceLayer::new_for_http()
         .make_span_with(tower_http::trace::DefaultMakeSpan::new().level(Level::INFO))
         .on_response(tower_http::trace::DefaultOnResponse::new().level(Level::INFO)),
     )
     .route("/health", get(health))
+    .route("/metrics", get(crate::metrics::handler))
     .route("/favicon.ico", get(favicon))
     .route("/assets/{*file}", get(static_handler))
-    .fallback_service(get(not_found));
+    .fallback_service(get(not_found))
+}
 
+pub async fn run_server(app: axum::Router) -> Result<(), Box<dyn std::error::Error>> {
   // https://docs.rs/axum/latest/axum/middleware/index.html#rewriting-request-uri-in-middleware
   // waiting for answer: https://github.com/tokio-rs/axum/discussions/3270
   let app = tower::util::MapRequestLayer::new(rewrite_request_uri).layer(app).into_make_service();
@@ -155,9 +473,8 @@ pub async fn run_server(app: axum::Router) -> Result<(), Box<dyn std::error::Err
   // let app = NormalizePathLayer::trim_trailing_slash().layer(app);
   // let app = ServiceExt::<Request>::into_make_service(app);
 
-  let host = std::env::var("HOST").unwrap_or("127.0.0.1".to_string());
-  let port = std::env::var("PORT").unwrap_or("8080".to_string());
-  let addr = format!("{}:{}", host, port);
+  let config = &crate::config::get().server;
+  let addr = format!("{}:{}", config.host, config.port);
 
   let listener = TcpListener::bind(&addr).await?;
   tracing::info!("listening on http://{}", addr);
@@ -0,0 +1,59 @@
+// `color=brand` with simple-icons brand colors
+mod common;
+
+use common::{Server, assert_badge, fetch_raw};
+
+fn colors(json: &[u8]) -> (String, String) {
+  let dat: serde_json::Value = serde_json::from_slice(json).unwrap();
+  (dat["lcolor"].to_string(), dat["rcolor"].to_string())
+}
+
+#[test]
+fn icon_brand() {
+  assert_badge("badge?label=a&value=b&icon=npm&color=brand", "a", "b", r#"{"Hex":"cb3837"}"#);
+  assert_badge("badge?label=a&value=b&logo=NPM&color=BRAND", "a", "b", r#"{"Hex":"cb3837"}"#);
+  assert_badge(
+    "crates/v/serde?icon=rust&color=brand",
+    "crates.io",
+    "v1.0.219",
+    r#"{"Hex":"000000"}"#,
+  );
+
+  let json =
+    fetch_raw("badge?label=a&value=b&icon=wechat&labelColor=brand&color=red", "json").unwrap();
+  assert_eq!(colors(&json), (r#"{"Hex":"07c160"}"#.into(), "\"Red\"".into()));
+
+  // integration brand is used without icon, as `fetch` goes through server middlewares
+  assert_badge("crates/v/serde?color=brand", "crates.io", "v1.0.219", r#"{"Hex":"000000"}"#);
+
+  // unknown icon or no icon keep defaults
+  assert_badge("badge?label=a&value=b&icon=unknown&color=brand", "a", "b", "DefaultValue");
+  let json = fetch_raw("badge?label=a&value=b&labelColor=brand", "json").unwrap();
+  assert_eq!(colors(&json).0, "\"DefaultLabel\"");
+}
+
+#[tokio::test]
+async fn integration_brand() {
+  let server = Server::start().await;
+  let get = async |path: &str| colors(&server.get(path).await.bytes().await.unwrap());
+
+  let npm = r#"{"Hex":"cb3837"}"#.to_string();
+  assert_eq!(get("/npm/v/react.json?color=brand").await.1, npm);
+  assert_eq!(get("/npm/v/react.json?labelColor=brand").await.0, npm);
+  assert_eq!(get("/crates/v/serde.json?color=brand").await.1, r#"{"Hex":"000000"}"#);
+
+  // only colors are replaced
+  let rep = server.get("/npm/v/react.json?label=brand&labelColor=brand").await;
+  let dat: serde_json::Value = serde_json::from_slice(&rep.bytes().await.unwrap()).unwrap();
+  assert_eq!(dat["llabel"], "brand");
+  assert_eq!(dat["lcolor"].to_string(), npm);
+
+  // icon wins over integration
+  assert_eq!(get("/npm/v/react.json?color=brand&icon=github").await.1, r#"{"Hex":"181717"}"#);
+
+  // error badges too
+  assert_eq!(get("/npm/v/status-404.json?labelColor=brand").await.0, npm);
+
+  // integrations without brand keep own colors
+  assert_eq!(get("/badge/a-b-green.json?labelColor=brand").await.0, "\"DefaultLabel\"");
+}
@@ -0,0 +1,72 @@
+// Upstream values cache, counters are from `/health`
+mod common;
+
+use common::Server;
+
+async fn hits(server: &Server) -> u64 {
+  let dat: serde_json::Value = server.get("/health").await.json().await.unwrap();
+  dat["cache"]["hit"].as_u64().unwrap()
+}
+
+#[tokio::test]
+async fn endpoint_cache_seconds() {
+  // nothing is fresh with zero ttl, unless value asks to be cached longer
+  let server = Server::start_with(&[("CACHE_TTL", "0")]).await;
+
+  for _ in 0..2 {
+    server.get("/endpoint?url=https://example.com/endpoint.json").await;
+  }
+  assert_eq!(hits(&server).await, 0);
+
+  for _ in 0..2 {
+    server.get("/endpoint?url=https://example.com/endpoint-cached.json").await;
+  }
+  assert_eq!(hits(&server).await, 1);
+}
+
+async fn upstream_404(server: &Server) -> u64 {
+  let text = server.get("/metrics").await.text().await.unwrap();
+  let line = text.lines().find(|x| {
+    x.starts_with("badges_upstream_request_duration_seconds_count") && x.contains("status=\"404\"")
+  });
+  line.and_then(|x| x.split(' ').next_back()?.parse().ok()).unwrap_or(0)
+}
+
+#[tokio::test]
+async fn refresh_backoff() {
+  let envs = [("CACHE_TTL", "2"), ("CACHE_REFRESH_AHEAD", "1")];
+  let server = Server::start_with(&envs).await;
+
+  // fixture disappears after first fetch, so every background refresh fails
+  let name = format!("refresh-{}.json", std::process::id());
+  let file = common::fixtures_dir().join("example.com").join(&name);
+  std::fs::write(&file, r#"{"version":"1.0"}"#).unwrap();
+  let url = format!("/dynamic/json?url=https://example.com/{name}&query=$.version");
+  let rep = server.get(&url).await;
+  std::fs::remove_file(&file).unwrap();
+  assert!(rep.status().is_success());
+
+  // without backoff it is retried every second
+  tokio::time::sleep(std::time::Duration::from_secs(6)).await;
+  let failed = upstream_404(&server).await;
+  assert!((1..=3).contains(&failed), "refreshed {failed} times");
+}
+
+#[tokio::test]
+async fn max_entries() {
+  let a = "/endpoint?url=https://example.com/endpoint.json";
+  let b = "/endpoint?url=https://example.com/endpoint-error.json";
+
+  // `b` pushes out least recently used `a`
+  let server = Server::start_with(&[("CACHE_MAX_ENTRIES", "1")]).await;
+  for path in [a, b, a] {
+    server.get(path).await;
+  }
+  assert_eq!(hits(&server).await, 0);
+
+  let server = Server::start_with(&[("CACHE_MAX_ENTRIES", "2")]).await;
+  for path in [a, b, a] {
+    server.get(path).await;
+  }
+  assert_eq!(hits(&server).await, 1);
+}
@@ -0,0 +1,218 @@
+use std::path::PathBuf;
+use std::process::{Child, Command};
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use axum::Router;
+use axum::body::Body;
+use axum::http::{StatusCode, Uri};
+use axum::response::{IntoResponse, Response};
+
+// MARK: Mock upstream
+
+// Upstream requests are rewritten to `{mock}/{host}/{path}` and served from `tests/fixtures`.
+// Directory path (or path with trailing slash) is served from `_index` file, query is ignored.
+// Special names in path make errors: `status-404`, `status-500`, `malformed`, `slow`, `large`
+// (2 MiB sent by chunks without `Content-Length`) and `rate-limited` (GitHub headers, reset in 1h).
+
+pub fn fixtures_dir() -> PathBuf {
+  PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+async fn serve_fixture(uri: Uri) -> Response {
+  let path = uri.path();
+  if path.contains("status-404") {
+    return (StatusCode::NOT_FOUND, "not found").into_response();
+  }
+
+  if path.contains("status-500") {
+    return (StatusCode::INTERNAL_SERVER_ERROR, "internal error").into_response();
+  }
+
+  if path.contains("rate-limited") {
+    let reset = (std::time::SystemTime::now() + Duration::from_secs(3600))
+      .duration_since(std::time::UNIX_EPOCH)
+      .unwrap()
+      .as_secs();
+    let headers =
+      [("x-ratelimit-remaining", "0".to_string()), ("x-ratelimit-reset", reset.to_string())];
+    return (StatusCode::FORBIDDEN, headers, "rate limit exceeded").into_response();
+  }
+
+  if path.contains("malformed") {
+    return (StatusCode::OK, "{\"version\": ").into_response();
+  }
+
+  if path.contains("slow") {
+    tokio::time::sleep(Duration::from_secs(5)).await;
+    return (StatusCode::OK, "{}").into_response();
+  }
+
+  if path.contains("large") {
+    let chunks = (0..32).map(|_| Ok::<_, std::io::Error>(vec![b' '; 64 * 1024]));
+    return (StatusCode::OK, Body::from_stream(futures_util::stream::iter(chunks))).into_response();
+  }
+
+  let mut file = fixtures_dir().join(path.trim_start_matches('/'));
+  if file.is_dir() {
+    file = file.join("_index");
+  }
+
+  match std::fs::read(&file) {
+    Ok(data) => (StatusCode::OK, data).into_response(),
+    Err(_) => (StatusCode::NOT_FOUND, format!("no fixture: {path}")).into_response(),
+  }
+}
+
+// Mock runs on own thread & runtime, so it is shared by all tests in binary
+pub fn mock_url() -> &'static str {
+  static URL: OnceLock<String> = OnceLock::new();
+  URL.get_or_init(|| {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("failed to bind mock");
+    listener.set_nonblocking(true).unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    std::thread::spawn(move || {
+      let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+      rt.block_on(async move {
+        let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+        let app = Router::new().fallback(serve_fixture);
+        axum::serve(listener, app).await.unwrap();
+      });
+    });
+
+    format!("http://{addr}")
+  })
+}
+
+// MARK: Badges CLI
+
+pub struct Fetched {
+  pub label: Option<String>,
+  pub value: String,
+  pub color: String,
+}
+
+// Binary with isolated env, which sends upstream requests to mock
+pub fn badges() -> Command {
+  let mut cmd = Command::new(env!("CARGO_BIN_EXE_badges"));
+  cmd
+    .env("UPSTREAM_BASE_URL", mock_url())
+    .env("UPSTREAM_RETRIES", "0")
+    .env("UPSTREAM_READ_TIMEOUT_MS", "500")
+    .env("CACHE_BACKEND", "memory")
+    .env("LOG_LEVEL", "off")
+    .env_remove("RUST_LOG")
+    .env_remove("RUST_BACKTRACE")
+    .env_remove("BADGES_CONFIG")
+    .env_remove("GITHUB_TOKENS")
+    .env_remove("GITHUB_TOKEN")
+    .env_remove("GITHUB_TOKENS_FILE")
+    .env_remove("GITLAB_URL")
+    .env_remove("ICONS_DIR")
+    .env_remove("REMOTE_ICONS");
+  cmd
+}
+
+// Runs `badges fetch <route> -o out.{ext}` against mock upstream, error is stderr of failed run
+pub fn fetch_raw(route: &str, ext: &str) -> Result<Vec<u8>, String> {
+  fetch_raw_with(route, ext, &[])
+}
+
+pub fn fetch_raw_with(route: &str, ext: &str, envs: &[(&str, &str)]) -> Result<Vec<u8>, String> {
+  static COUNTER: AtomicUsize = AtomicUsize::new(0);
+  let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+  let out = PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+    .join(format!("badge-{}-{id}.{ext}", std::process::id()));
+
+  // route is given as url, so path segments stay encoded and query values are decoded
+  let url = reqwest::Url::parse(&format!("http://localhost/{}", route.trim_start_matches('/')))
+    .map_err(|e| format!("invalid route {route}: {e}"))?;
+
+  let mut cmd = badges();
+  cmd.envs(envs.iter().copied()).arg("fetch").arg("-o").arg(&out);
+  for (k, v) in url.query_pairs().filter(|(k, _)| !k.is_empty()) {
+    cmd.arg("-q").arg(format!("{k}={v}"));
+  }
+  cmd.arg("--").args(url.path_segments().unwrap()); // segments can start with dash
+
+  let rep = cmd.output().expect("failed to run badges");
+
+  if !rep.status.success() {
+    return Err(String::from_utf8_lossy(&rep.stderr).to_string());
+  }
+
+  let data = std::fs::read(&out).expect("no output file");
+  let _ = std::fs::remove_file(&out);
+  Ok(data)
+}
+
+pub fn fetch(route: &str) -> Result<Fetched, String> {
+  let data = fetch_raw(route, "json")?;
+  let dat: serde_json::Value = serde_json::from_slice(&data).expect("invalid json output");
+  Ok(Fetched {
+    label: dat["llabel"].as_str().map(|x| x.to_string()),
+    value: dat["rlabel"].as_str().unwrap_or_default().to_string(),
+    color: dat["rcolor"].to_string().trim_matches('"').to_string(),
+  })
+}
+
+// Asserts `(label, value, color)` of fetched badge, color as in JSON output: `Green`, `{"Hex":..}`
+#[track_caller]
+pub fn assert_badge(route: &str, label: &str, value: &str, color: &str) {
+  let rs = fetch(route).unwrap_or_else(|e| panic!("{route}: {e}"));
+  assert_eq!(rs.label.as_deref(), Some(label), "label of {route}");
+  assert_eq!(rs.value, value, "value of {route}");
+  assert_eq!(rs.color, color, "color of {route}");
+}
+
+#[track_caller]
+pub fn assert_failed(route: &str, message: &str) {
+  match fetch(route) {
+    Ok(rs) => panic!("{route}: expected error, got {:?} {}", rs.label, rs.value),
+    Err(e) => assert!(e.contains(message), "{route}: expected {message:?} in {e:?}"),
+  }
+}
+
+// MARK: Badges server
+
+// Running `badges serve`, killed on drop
+pub struct Server {
+  pub url: String,
+  child: Child,
+}
+
+impl Drop for Server {
+  fn drop(&mut self) {
+    let _ = self.child.kill();
+    let _ = self.child.wait();
+  }
+}
+
+impl Server {
+  pub async fn start() -> Server {
+    Server::start_with(&[]).await
+  }
+
+  pub async fn start_with(envs: &[(&str, &str)]) -> Server {
+    let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+    let mut cmd = badges();
+    cmd.envs(envs.iter().copied());
+    let child = cmd.arg("serve").env("PORT", port.to_string()).spawn().unwrap();
+    let server = Server { url: format!("http://127.0.0.1:{port}"), child };
+
+    for _ in 0..100 {
+      if reqwest::get(format!("{}/health", server.url)).await.is_ok() {
+        return server;
+      }
+      tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    panic!("server is not started on {}", server.url);
+  }
+
+  pub async fn get(&self, path: &str) -> reqwest::Response {
+    reqwest::get(format!("{}{path}", self.url)).await.unwrap()
+  }
+}
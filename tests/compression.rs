@@ -0,0 +1,111 @@
+// `Accept-Encoding` negotiation for SVG, JSON and HTML responses
+mod common;
+
+use std::io::Read;
+
+use common::Server;
+use reqwest::StatusCode;
+use reqwest::header::{ACCEPT_ENCODING, CONTENT_ENCODING, ETAG, IF_NONE_MATCH, VARY};
+
+async fn get_encoded(server: &Server, path: &str, accept: &str) -> reqwest::Response {
+  let client = reqwest::Client::new();
+  let req = client.get(format!("{}{path}", server.url)).header(ACCEPT_ENCODING, accept);
+  req.send().await.unwrap()
+}
+
+fn header(rep: &reqwest::Response, name: reqwest::header::HeaderName) -> Option<String> {
+  rep.headers().get(name).map(|x| x.to_str().unwrap().to_string())
+}
+
+fn decode(encoding: &str, data: &[u8]) -> Vec<u8> {
+  let mut out = vec![];
+  match encoding {
+    "br" => brotli::BrotliDecompress(&mut &data[..], &mut out).unwrap(),
+    "zstd" => out = zstd::decode_all(data).unwrap(),
+    "gzip" => _ = flate2::read::GzDecoder::new(data).read_to_end(&mut out).unwrap(),
+    x => panic!("unexpected encoding {x}"),
+  }
+  out
+}
+
+#[tokio::test]
+async fn negotiation() {
+  let server = Server::start().await;
+  let cases = [
+    ("gzip, deflate, br, zstd", Some("br")),
+    ("gzip, zstd", Some("zstd")),
+    ("gzip", Some("gzip")),
+    ("GZIP", Some("gzip")),
+    ("br;q=0.5, gzip;q=0.8", Some("gzip")),
+    ("br;q=0, *", Some("zstd")),
+    ("*;q=0, gzip", Some("gzip")),
+    ("deflate", None),
+    ("identity", None),
+    ("br;q=0", None),
+  ];
+
+  let path = "/crates/v/serde?icon=rust";
+  for (accept, expected) in cases {
+    let rep = get_encoded(&server, path, accept).await;
+    assert_eq!(header(&rep, CONTENT_ENCODING).as_deref(), expected, "{accept}");
+    assert_eq!(header(&rep, VARY).as_deref(), Some("accept-encoding"), "{accept}");
+  }
+}
+
+#[tokio::test]
+async fn content() {
+  let server = Server::start().await;
+
+  let long = format!("/badge/{}-passing-green.json", "build".repeat(60));
+  for path in ["/crates/v/serde?icon=rust", &long, "/"] {
+    let plain = server.get(path).await;
+    assert_eq!(header(&plain, CONTENT_ENCODING), None, "{path}");
+    let plain = plain.bytes().await.unwrap();
+
+    for encoding in ["br", "zstd", "gzip"] {
+      let rep = get_encoded(&server, path, encoding).await;
+      assert_eq!(header(&rep, CONTENT_ENCODING).as_deref(), Some(encoding), "{path}");
+      let data = rep.bytes().await.unwrap();
+      assert!(data.len() < plain.len(), "{path} {encoding}");
+      assert_eq!(decode(encoding, &data), plain, "{path} {encoding}");
+
+      // second one is served from cache of compressed badges
+      let again = get_encoded(&server, path, encoding).await.bytes().await.unwrap();
+      assert_eq!(again, data, "{path} {encoding}");
+    }
+  }
+}
+
+#[tokio::test]
+async fn skipped() {
+  let server = Server::start().await;
+
+  // png is compressed already, small responses are not worth it
+  for path in ["/crates/v/serde.png", "/crates/v/serde.json", "/health"] {
+    let rep = get_encoded(&server, path, "gzip").await;
+    assert_eq!(rep.status(), StatusCode::OK, "{path}");
+    assert_eq!(header(&rep, CONTENT_ENCODING), None, "{path}");
+  }
+}
+
+#[tokio::test]
+async fn etag() {
+  let server = Server::start().await;
+  let path = "/crates/v/serde?icon=rust";
+
+  let strong = header(&server.get(path).await, ETAG).unwrap();
+  let rep = get_encoded(&server, path, "gzip").await;
+  let weak = header(&rep, ETAG).unwrap();
+  assert_eq!(weak, format!("W/{strong}"));
+
+  // both tags revalidate compressed and plain responses, 304 has same tag as 200 would have
+  let client = reqwest::Client::new();
+  for (tag, accept, expected) in
+    [(&weak, "gzip", &weak), (&strong, "gzip", &weak), (&weak, "identity", &strong)]
+  {
+    let req = client.get(format!("{}{path}", server.url));
+    let rep = req.header(IF_NONE_MATCH, tag).header(ACCEPT_ENCODING, accept).send().await.unwrap();
+    assert_eq!(rep.status(), StatusCode::NOT_MODIFIED, "{tag} {accept}");
+    assert_eq!(header(&rep, ETAG).as_ref(), Some(expected), "{tag} {accept}");
+  }
+}
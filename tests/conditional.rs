@@ -0,0 +1,91 @@
+// ETag, Last-Modified and `304 Not Modified` handling
+mod common;
+
+use common::Server;
+use reqwest::StatusCode;
+use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+
+async fn get_with(server: &Server, path: &str, name: &str, value: &str) -> reqwest::Response {
+  let client = reqwest::Client::new();
+  client.get(format!("{}{path}", server.url)).header(name, value).send().await.unwrap()
+}
+
+fn header(rep: &reqwest::Response, name: reqwest::header::HeaderName) -> Option<String> {
+  rep.headers().get(name).map(|x| x.to_str().unwrap().to_string())
+}
+
+#[tokio::test]
+async fn etag() {
+  let server = Server::start().await;
+
+  let mut tags = vec![];
+  for path in
+    ["/badge/build-passing-green", "/badge/build-passing-green.json", "/crates/v/serde.png"]
+  {
+    let a = header(&server.get(path).await, ETAG).expect("no etag");
+    let b = header(&server.get(path).await, ETAG).unwrap();
+    assert_eq!(a, b, "{path}");
+    assert!(a.starts_with('"') && a.ends_with('"'), "{a}");
+    tags.push(a);
+  }
+
+  tags.dedup();
+  assert_eq!(tags.len(), 3, "each format has own etag");
+
+  // tag is content hash, so it's the same on another instance
+  let other = Server::start().await;
+  let a = header(&server.get("/crates/v/serde").await, ETAG);
+  let b = header(&other.get("/crates/v/serde").await, ETAG);
+  assert_eq!(a, b);
+}
+
+#[tokio::test]
+async fn if_none_match() {
+  let server = Server::start().await;
+  let path = "/crates/v/serde";
+  let etag = header(&server.get(path).await, ETAG).unwrap();
+
+  for value in [etag.clone(), format!("W/{etag}"), format!("\"other\", {etag}"), "*".into()] {
+    let rep = get_with(&server, path, IF_NONE_MATCH.as_str(), &value).await;
+    assert_eq!(rep.status(), StatusCode::NOT_MODIFIED, "{value}");
+    assert_eq!(header(&rep, ETAG).as_ref(), Some(&etag));
+    assert!(header(&rep, reqwest::header::CACHE_CONTROL).is_some());
+    assert!(rep.bytes().await.unwrap().is_empty());
+  }
+
+  let rep = get_with(&server, path, IF_NONE_MATCH.as_str(), "\"other\"").await;
+  assert_eq!(rep.status(), StatusCode::OK);
+
+  // other options make other badge
+  let rep = get_with(&server, "/crates/v/serde?style=flat-square", "if-none-match", &etag).await;
+  assert_eq!(rep.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn last_modified() {
+  let server = Server::start().await;
+
+  // static badges are not fetched from anywhere
+  assert_eq!(header(&server.get("/badge/build-passing-green").await, LAST_MODIFIED), None);
+  assert_eq!(header(&server.get("/crates/v/status-404").await, LAST_MODIFIED), None);
+
+  let path = "/crates/v/serde";
+  let modified = header(&server.get(path).await, LAST_MODIFIED).expect("no last-modified");
+  assert!(modified.ends_with(" GMT"), "{modified}");
+
+  // value is cached, so time is kept between requests
+  assert_eq!(header(&server.get(path).await, LAST_MODIFIED).as_ref(), Some(&modified));
+
+  let rep = get_with(&server, path, IF_MODIFIED_SINCE.as_str(), &modified).await;
+  assert_eq!(rep.status(), StatusCode::NOT_MODIFIED);
+
+  let old = "Sat, 01 Jan 2000 00:00:00 GMT";
+  let rep = get_with(&server, path, IF_MODIFIED_SINCE.as_str(), old).await;
+  assert_eq!(rep.status(), StatusCode::OK);
+
+  // etag wins over date
+  let client = reqwest::Client::new();
+  let req = client.get(format!("{}{path}", server.url));
+  let req = req.header(IF_MODIFIED_SINCE, &modified).header(IF_NONE_MATCH, "\"other\"");
+  assert_eq!(req.send().await.unwrap().status(), StatusCode::OK);
+}
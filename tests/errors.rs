@@ -0,0 +1,141 @@
+// Upstream failures should fail `badges fetch`, mock server makes them by path name
+mod common;
+
+use std::time::{Duration, Instant};
+
+use common::{Server, assert_badge, assert_failed};
+
+#[test]
+fn upstream_status() {
+  assert_failed("crates/v/status-404", "404 Not Found");
+  assert_failed("npm/v/status-404", "404 Not Found");
+  assert_failed("crates/v/status-500", "500 Internal Server Error");
+  assert_failed("github/stars/vladkens/status-500", "500 Internal Server Error");
+}
+
+#[test]
+fn malformed_json() {
+  assert_failed("crates/v/malformed", "error decoding response body");
+  assert_failed("pypi/v/malformed", "error decoding response body");
+  assert_failed("endpoint?url=https://example.com/malformed.json", "invalid endpoint json");
+}
+
+#[test]
+fn missing_value() {
+  let route = "dynamic/json?url=https://example.com/data.json&query=$.missing";
+  assert_failed(route, "no result for query: $.missing");
+}
+
+#[test]
+fn large_document() {
+  // sent without `Content-Length`, so limit is checked while reading
+  let route = "dynamic/json?url=https://example.com/large&query=$.version";
+  assert_failed(route, "response too large: over 1048576 bytes");
+  assert_failed("endpoint?url=https://example.com/large", "response too large: over 65536 bytes");
+}
+
+#[test]
+fn timeout() {
+  // mock responds in 5s, while read timeout is 500ms
+  let now = Instant::now();
+  assert_failed("crates/v/slow", "error sending request");
+  assert!(now.elapsed() < Duration::from_secs(4), "took {:?}", now.elapsed());
+}
+
+#[test]
+fn unknown_route() {
+  assert_failed("unknown/v/serde", "404 Not Found");
+  assert_failed("crates/unknown/serde", "unknown variant `unknown`");
+}
+
+#[test]
+fn soft_errors() {
+  // repo without coverage is not an error, but missing repo is
+  assert_badge("codecov/c/github/vladkens/no-coverage", "coverage", "unknown", "Grey");
+  assert_failed("codecov/c/github/vladkens/status-404", "404 Not Found");
+}
+
+// MARK: Error badges
+
+async fn error_badge(server: &Server, path: &str) -> (u16, serde_json::Value) {
+  let rep = server.get(path).await;
+  let status = rep.status().as_u16();
+  (status, rep.json().await.unwrap())
+}
+
+#[tokio::test]
+async fn error_kinds() {
+  let server = Server::start().await;
+  let cases = [
+    ("/crates/v/status-404.json", "not_found", "package not found"),
+    ("/crates/v/status-500.json", "upstream_status", "500 Internal Server Error"),
+    ("/crates/v/malformed.json", "upstream_parse", "invalid response"),
+    ("/crates/v/slow.json", "upstream_timeout", "timeout"),
+    ("/crates/unknown/serde.json", "unsupported_kind", "unsupported"),
+    ("/dynamic/json.json?query=$.version", "invalid_params", "invalid"),
+    ("/jetbrains/v/0.json", "not_found", "package not found"), // empty list in response
+  ];
+
+  for (path, kind, text) in cases {
+    let (status, dat) = error_badge(&server, path).await;
+    assert_eq!(status, 200, "{path}");
+    assert_eq!(dat["error"], kind, "{path}");
+    assert_eq!(dat["llabel"], "error", "{path}");
+    assert_eq!(dat["rlabel"], text, "{path}");
+    assert_eq!(dat["rcolor"], "Red", "{path}");
+  }
+
+  // successful badge has no error field
+  let (_, dat) = error_badge(&server, "/crates/v/serde.json").await;
+  assert!(dat.get("error").is_none());
+}
+
+#[tokio::test]
+async fn error_options() {
+  let server = Server::start().await;
+
+  // style, format and label are taken from request, but color is not
+  let path = "/crates/v/status-404.json?label=crates&style=flat-square&color=green";
+  let (_, dat) = error_badge(&server, path).await;
+  assert_eq!(dat["llabel"], "crates");
+  assert_eq!(dat["style"], "FlatSquare");
+  assert_eq!(dat["rcolor"], "Red");
+
+  let rep = server.get("/crates/v/status-404").await;
+  assert_eq!(rep.headers()["content-type"], "image/svg+xml");
+  assert!(rep.text().await.unwrap().contains("package not found"));
+}
+
+#[tokio::test]
+async fn rate_limited() {
+  let server = Server::start().await;
+  let rep = server.get("/github/stars/vladkens/rate-limited.json").await;
+
+  // cached until limit reset in 1h
+  let cc = rep.headers()["cache-control"].to_str().unwrap().to_string();
+  let max_age = cc.split(',').find_map(|x| x.strip_prefix("max-age=")?.parse::<u32>().ok());
+  assert!(max_age.is_some_and(|x| (3500..=3600).contains(&x)), "{cc}");
+
+  let dat: serde_json::Value = rep.json().await.unwrap();
+  assert_eq!(dat["error"], "rate_limited");
+  assert_eq!(dat["rcolor"], "Orange");
+}
+
+#[tokio::test]
+async fn strict_mode() {
+  let server = Server::start().await;
+  let cases = [
+    ("/crates/v/serde?strict=1", 200),
+    ("/crates/v/status-404?strict=1", 404),
+    ("/crates/v/status-500?strict=1", 502),
+    ("/crates/v/malformed?strict=1", 502),
+    ("/crates/v/slow?strict=1", 504),
+    ("/crates/unknown/serde?strict=1", 400),
+    ("/dynamic/json?query=$.version&strict=1", 400),
+    ("/crates/v/status-404?strict=0", 200),
+  ];
+
+  for (path, status) in cases {
+    assert_eq!(server.get(path).await.status().as_u16(), status, "{path}");
+  }
+}
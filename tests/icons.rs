@@ -0,0 +1,133 @@
+// Custom icons: data uri, remote url and directory from config, all sanitized
+mod common;
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use common::{Server, badges, fetch_raw};
+
+const SVG: &str = r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" onclick="alert(1)"><script>alert(1)</script><rect width="24" height="24" fill="#f80"/></svg>"##;
+const PNG: &[u8] = &[0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a, 0, 0, 0, 0x0d];
+
+fn data_uri(mime: &str, data: &[u8]) -> String {
+  format!("data:{mime};base64,{}", BASE64_STANDARD.encode(data))
+}
+
+// Embedded icon of rendered svg as `(mime, content)`
+fn icon_of(svg: &[u8]) -> Option<(String, Vec<u8>)> {
+  let svg = String::from_utf8_lossy(svg);
+  let uri = svg.split("<image").nth(1)?.split("href=\"").nth(1)?.split('"').next()?;
+  let (meta, data) = uri.strip_prefix("data:")?.split_once(";base64,")?;
+  Some((meta.to_string(), BASE64_STANDARD.decode(data).unwrap()))
+}
+
+#[track_caller]
+fn assert_sanitized(svg: &[u8]) -> String {
+  let (mime, data) = icon_of(svg).expect("no icon");
+  assert_eq!(mime, "image/svg+xml");
+
+  let data = String::from_utf8(data).unwrap();
+  for x in ["script", "alert", "onload", "onclick", "javascript:", "foreignObject", "tracker.png"] {
+    assert!(!data.contains(x), "{x} in {data}");
+  }
+  data
+}
+
+#[test]
+fn data_uri_icons() {
+  // `+` of mime and base64 is space after query decoding, so it's encoded as in real links
+  let route = |icon: &str| format!("/badge/a-b-green?icon={}", icon.replace('+', "%2B"));
+
+  let svg = fetch_raw(&route(&data_uri("image/svg+xml", SVG.as_bytes())), "svg").unwrap();
+  let icon = assert_sanitized(&svg);
+  assert!(icon.contains("#ff8800") || icon.contains("#f80"), "{icon}");
+
+  let svg = fetch_raw(&route(&data_uri("image/png", PNG)), "svg").unwrap();
+  assert_eq!(icon_of(&svg), Some(("image/png".to_string(), PNG.to_vec())));
+
+  // mime is taken from content, not from uri
+  let svg = fetch_raw(&route(&data_uri("image/png", SVG.as_bytes())), "svg").unwrap();
+  assert_sanitized(&svg);
+
+  let invalid = [
+    data_uri("text/html", b"<html><script>alert(1)</script></html>"),
+    data_uri("image/svg+xml", b"<svg xmlns=\"http://www.w3.org/2000/svg\"></svg>"),
+    "data:image/svg+xml;base64,%%%".to_string(),
+  ];
+
+  for icon in invalid {
+    let svg = fetch_raw(&route(&icon), "svg").unwrap();
+    assert_eq!(icon_of(&svg), None, "{}", &icon[..40.min(icon.len())]);
+  }
+}
+
+#[test]
+fn icons_dir() {
+  let dir = std::path::PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("icons");
+  let _ = std::fs::remove_dir_all(&dir);
+  std::fs::create_dir_all(&dir).unwrap();
+  std::fs::write(dir.join("Product.svg"), SVG).unwrap();
+  std::fs::write(dir.join("notes.txt"), "not an icon").unwrap();
+
+  let out = dir.join("out.svg");
+  let render = |icon: &str| {
+    let mut cmd = badges();
+    cmd.env("ICONS_DIR", &dir).arg("fetch").arg("-o").arg(&out);
+    let rep = cmd.args(["-q", &format!("icon={icon}"), "--", "badge", "a-b-green"]).output();
+    let rep = rep.unwrap();
+    assert!(rep.status.success(), "{}", String::from_utf8_lossy(&rep.stderr));
+    std::fs::read(&out).unwrap()
+  };
+
+  assert_sanitized(&render("product"));
+  assert_sanitized(&render("PRODUCT"));
+
+  // simple-icons are still there
+  let (mime, data) = icon_of(&render("rust")).unwrap();
+  assert_eq!(mime, "image/svg+xml");
+  assert!(String::from_utf8(data).unwrap().contains("viewBox=\"0 0 24 24\""));
+
+  // broken icon fails on startup
+  std::fs::write(dir.join("broken.svg"), "<svg").unwrap();
+  let mut cmd = badges();
+  let rep = cmd.env("ICONS_DIR", &dir).args(["fetch", "--", "badge", "a-b-green"]).output();
+  let rep = rep.unwrap();
+  assert!(!rep.status.success());
+  assert!(String::from_utf8_lossy(&rep.stderr).contains("broken.svg"));
+
+  let rep =
+    badges().env("ICONS_DIR", dir.join("missing")).args(["render", "--value", "x"]).output();
+  let stderr = String::from_utf8_lossy(&rep.unwrap().stderr).to_string();
+  assert!(stderr.contains("badge.icons_dir: not a directory"), "{stderr}");
+}
+
+#[tokio::test]
+async fn remote_icons() {
+  // disabled by default, so server can't be used to reach internal hosts
+  let server = Server::start().await;
+  let rep = server.get("/badge/a-b-green?icon=https://example.com/logo.svg").await;
+  assert_eq!(icon_of(&rep.bytes().await.unwrap()), None);
+
+  let server = Server::start_with(&[("REMOTE_ICONS", "true")]).await;
+
+  // fetched from mock as `example.com/logo.svg`
+  let rep = server.get("/badge/a-b-green?icon=https://example.com/logo.svg").await;
+  assert_eq!(rep.status(), 200);
+  let icon = assert_sanitized(&rep.bytes().await.unwrap());
+  assert!(icon.contains("#e34c26"), "{icon}");
+
+  // same for error badges
+  let rep = server.get("/crates/v/status-404?logo=https://example.com/logo.svg").await;
+  assert_sanitized(&rep.bytes().await.unwrap());
+
+  // badge is rendered without icon if it can't be fetched or used
+  for url in [
+    "https://example.com/status-404",
+    "https://example.com/data.json",
+    "https://example.com/large",
+    "http://example.com/logo.svg",
+  ] {
+    let rep = server.get(&format!("/badge/a-b-green?icon={url}")).await;
+    assert_eq!(rep.status(), 200, "{url}");
+    assert_eq!(icon_of(&rep.bytes().await.unwrap()), None, "{url}");
+  }
+}
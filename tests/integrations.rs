@@ -0,0 +1,249 @@
+// Every integration is fetched from recorded fixtures, see `common::mock_url`
+mod common;
+
+use common::assert_badge;
+
+#[test]
+fn amo() {
+  assert_badge("amo/v/ublock-origin", "mozilla add-on", "v1.62.0", "Blue");
+  assert_badge("amo/l/ublock-origin", "license", "gpl-3.0", "Blue");
+  assert_badge("amo/dw/ublock-origin", "downloads", "154.3k/week", "Green");
+  assert_badge("amo/rating/ublock-origin", "rating", "4.8/5", "Green");
+  assert_badge("amo/users/ublock-origin", "users", "9.1M", "Blue");
+}
+
+#[test]
+fn clojars() {
+  assert_badge("clojars/v/ring/ring-core", "clojars", "[ring/ring-core \"1.13.0\"]", "Blue");
+  assert_badge("clojars/l/ring/ring-core", "license", "The MIT License", "Blue");
+  assert_badge("clojars/dt/ring/ring-core", "downloads", "22.8M", "Green");
+}
+
+#[test]
+fn cocoapods() {
+  assert_badge("cocoapods/v/Alamofire", "pod", "v5.10.2", "Blue");
+  assert_badge("cocoapods/l/Alamofire", "license", "MIT", "Blue");
+  assert_badge("cocoapods/p/Alamofire", "platform", "ios | osx", "DefaultValue");
+}
+
+#[test]
+fn codecov() {
+  assert_badge("codecov/c/github/vladkens/badges", "coverage", "87%", "Yellow");
+}
+
+#[test]
+fn cpan() {
+  assert_badge("cpan/v/Moose", "cpan", "v2.2207", "Blue");
+  assert_badge("cpan/l/Moose", "license", "perl_5", "Blue");
+}
+
+#[test]
+fn crates() {
+  assert_badge("crates/v/serde", "crates.io", "v1.0.219", "Blue");
+  assert_badge("crates/l/serde", "license", "MIT OR Apache-2.0", "Blue");
+  assert_badge("crates/dw/serde", "downloads", "7M/week", "Green");
+  assert_badge("crates/dm/serde", "downloads", "28M/month", "Green");
+  assert_badge("crates/dt/serde", "downloads", "512.3M", "Green");
+  assert_badge("crates/msrv/serde", "msrv", "v1.31", "Blue");
+  assert_badge("crates/docs/serde", "docs", "passing", "Green");
+}
+
+#[test]
+fn cws() {
+  assert_badge("cws/v/abc", "chrome web store", "v1.62.0", "Blue");
+  assert_badge("cws/rating/abc", "rating", "4.5/5", "Green");
+  assert_badge("cws/rating/abc?thresholds=0:red,4.6:blue", "rating", "4.5/5", "Red");
+  assert_badge("cws/rating/abc?color=blue", "rating", "4.5/5", "Blue");
+  assert_badge("cws/rating-count/abc", "ratings", "1.2k", "Blue");
+  assert_badge("cws/stars/abc", "stars", "★★★★½", "Green");
+  assert_badge("cws/users/abc", "users", "10,000,000+", "DefaultValue");
+}
+
+#[test]
+fn dartpub() {
+  assert_badge("pub/v/http", "pub", "v1.3.0", "Blue");
+  assert_badge("pub/l/http", "license", "bsd-3-clause", "Blue");
+  assert_badge("pub/dw/http", "downloads", "1M/week", "Green");
+  assert_badge("pub/dm/http", "downloads", "4M/month", "Green");
+}
+
+#[test]
+fn discord() {
+  assert_badge("discord/308323056592486420", "discord", "12345 online", "DefaultValue");
+}
+
+#[test]
+fn docker() {
+  assert_badge("docker/v/library/nginx", "image version", "v1.27.4", "Blue");
+  assert_badge("docker/image-size/library/nginx", "image size", "67.7 MiB", "Blue");
+  assert_badge("docker/pulls/library/nginx", "docker pulls", "1.2B", "Blue");
+  assert_badge("docker/stars/library/nginx", "docker stars", "20.5k", "Blue");
+  assert_badge("docker/automated/library/nginx", "docker build", "manual", "DefaultValue");
+}
+
+#[test]
+fn gems() {
+  assert_badge("gem/v/rails", "gem", "v8.0.2", "Blue");
+  assert_badge("gem/l/rails", "license", "MIT", "Blue");
+  assert_badge("gem/dt/rails", "downloads", "4M", "Green");
+  assert_badge("gem/ruby/rails", "ruby", "≥ 3.2.0", "Blue");
+}
+
+#[test]
+fn github() {
+  assert_badge("github/release/vladkens/badges", "release", "v0.4.1", "Orange");
+  assert_badge("github/assets-dl/vladkens/badges", "downloads", "4.5k", "Green");
+  assert_badge("github/l/vladkens/badges", "license", "MIT", "Blue");
+  assert_badge("github/stars/vladkens/badges", "stars", "1.2k", "Blue");
+  assert_badge("github/forks/vladkens/badges", "forks", "56", "Blue");
+  assert_badge("github/watchers/vladkens/badges", "watchers", "1.2k", "Blue");
+  assert_badge("github/last-commit/vladkens/badges", "last commit", "long ago", "Grey");
+  assert_badge("github/repo-size/vladkens/badges", "repo size", "2 MiB", "Blue");
+  assert_badge("github/lang-top/vladkens/badges", "Rust", "75.0%", "DefaultValue");
+  assert_badge("github/lang-count/vladkens/badges", "lang count", "3", "Blue");
+  assert_badge("github/lang-size/vladkens/badges", "code size", "97.7 KiB", "Blue");
+  assert_badge("github/workflow/vladkens/badges/build.yml", "build", "passing", "Green");
+}
+
+#[test]
+fn gitlab() {
+  let name = "gitlab-org/gitlab";
+  assert_badge(&format!("gitlab/pipeline/{name}"), "pipeline", "passed", "Green");
+  assert_badge(&format!("gitlab/coverage/{name}"), "coverage", "92%", "Green");
+  assert_badge(&format!("gitlab/release/{name}"), "release", "v17.5.0", "Blue");
+  assert_badge(&format!("gitlab/tag/{name}"), "tag", "v17.5.1", "Blue");
+  assert_badge(&format!("gitlab/stars/{name}"), "stars", "5k", "Blue");
+  assert_badge(&format!("gitlab/forks/{name}"), "forks", "10.5k", "Blue");
+  assert_badge(&format!("gitlab/issues/{name}"), "open issues", "45k", "Blue");
+  assert_badge(&format!("gitlab/mrs/{name}"), "open MRs", "10k+", "Blue"); // no `x-total` header
+  assert_badge(&format!("gitlab/l/{name}"), "license", "Other", "Blue");
+  assert_badge(&format!("gitlab/last-commit/{name}"), "last commit", "long ago", "Grey");
+}
+
+#[test]
+fn hackage() {
+  assert_badge("hackage/v/aeson", "hackage", "v2.2.3.0", "Blue");
+  assert_badge("hackage/l/aeson", "license", "BSD-3-Clause", "Blue");
+}
+
+#[test]
+fn hexpm() {
+  assert_badge("hexpm/v/phoenix", "hex", "v1.7.21", "Blue");
+  assert_badge("hexpm/l/phoenix", "license", "MIT", "Blue");
+  assert_badge("hexpm/dw/phoenix", "downloads", "350k/week", "Green");
+  assert_badge("hexpm/dm/phoenix", "downloads", "1.5M/month", "Green");
+  assert_badge("hexpm/dt/phoenix", "downloads", "98.8M", "Green");
+}
+
+#[test]
+fn homebrew() {
+  assert_badge("homebrew/v/wget", "homebrew", "v1.25.0", "Blue");
+  assert_badge("homebrew/dm/wget", "downloads", "45.7k/month", "Green");
+  assert_badge("homebrew/dy/wget", "downloads", "134.6k/year", "Green");
+  assert_badge("homebrew/v/cask/firefox", "homebrew", "v137.0.2", "Blue");
+  assert_badge("homebrew/dm/cask/firefox", "downloads", "23.5k/month", "Green");
+}
+
+#[test]
+fn jetbrains() {
+  assert_badge("jetbrains/v/7724", "jetbrain plugin", "v251.23774.318", "Blue");
+  assert_badge("jetbrains/dt/7724", "downloads", "42.3M", "Green");
+  assert_badge("jetbrains/score/7724", "score", "4.2/5", "Green");
+  assert_badge("jetbrains/score/7724?thresholds=0:red,4.5:green", "score", "4.2/5", "Red");
+  assert_badge("jetbrains/stars/7724", "stars", "★★★★☆", "Green");
+  assert_badge("jetbrains/stars/7724?thresholds=0:red,4.5:green", "stars", "★★★★☆", "Red");
+}
+
+#[test]
+fn npm() {
+  assert_badge("npm/v/react", "npm", "v19.1.0", "Blue");
+  assert_badge("npm/l/react", "license", "MIT", "Blue");
+  assert_badge("npm/dw/react", "downloads", "15M/week", "Green");
+  assert_badge("npm/dm/react", "downloads", "90M/month", "Green");
+  assert_badge("npm/dt/react", "downloads", "3.3B", "Green");
+}
+
+#[test]
+fn nuget() {
+  assert_badge("nuget/v/Newtonsoft.Json", "nuget", "v13.0.3", "Blue");
+  assert_badge("nuget/dt/Newtonsoft.Json", "downloads", "5.1B", "Green");
+}
+
+#[test]
+fn packagephobia() {
+  assert_badge("packagephobia/publish/react", "publish size", "318 kB", r#"{"Hex":"44cc11"}"#);
+  assert_badge("packagephobia/install/react", "install size", "1.52 MB", r#"{"Hex":"97ca00"}"#);
+}
+
+#[test]
+fn packagist() {
+  let name = "laravel/framework";
+  assert_badge(&format!("packagist/v/{name}"), "packagist", "v12.1.0", "Blue");
+  assert_badge(&format!("packagist/l/{name}"), "license", "MIT", "Blue");
+  assert_badge(&format!("packagist/dw/{name}"), "downloads", "2.2M/week", "Green");
+  assert_badge(&format!("packagist/dm/{name}"), "downloads", "9M/month", "Green");
+  assert_badge(&format!("packagist/dt/{name}"), "downloads", "456.8M", "Green");
+  assert_badge(&format!("packagist/php/{name}"), "php", "^8.2", "Blue");
+}
+
+#[test]
+fn puppetforge() {
+  assert_badge("puppetforge/version/puppetlabs/stdlib", "puppetforge", "v9.7.0", "Blue");
+  assert_badge("puppetforge/dt/puppetlabs/stdlib", "downloads", "98.8M", "Green");
+  assert_badge("puppetforge/score/puppetlabs/stdlib", "quality score", "100%", "DefaultValue");
+}
+
+#[test]
+fn pypi() {
+  assert_badge("pypi/v/requests", "pypi", "v2.32.3", "Blue");
+  assert_badge("pypi/l/requests", "license", "Apache-2.0", "Blue");
+  assert_badge("pypi/dw/requests", "downloads", "130M/week", "Green");
+  assert_badge("pypi/dm/requests", "downloads", "520M/month", "Green");
+  assert_badge("pypi/dt/requests", "downloads", "3.5M", "Green");
+  assert_badge("pypi/python/requests", "python", "3 – 3.12", "DefaultValue");
+  assert_badge("pypi/wheel/requests", "wheel", "yes", "DefaultValue");
+  assert_badge("pypi/status/requests", "status", "stable", "Green");
+  assert_badge("pypi/implementation/requests", "implementation", "cpython | pypy", "DefaultValue");
+}
+
+#[test]
+fn readthedocs() {
+  assert_badge("readthedocs/requests", "docs", "passing", "Green");
+}
+
+#[test]
+fn vscode() {
+  assert_badge("vscode/v/ms-python.python", "vscode", "v0.3.2379", "Orange");
+  assert_badge("vscode/i/ms-python.python", "installs", "5.4M", "Green");
+  assert_badge("vscode/i/ms-python.python?thresholds=0:red,1e7:green", "installs", "5.4M", "Red");
+  assert_badge("vscode/i/ms-python.python?label=users", "users", "5.4M", "Green");
+  assert_badge("vscode/d/ms-python.python", "downloads", "17.4M", "Green");
+  assert_badge("vscode/d/ms-python.python?thresholds=0:red,1e8:green", "downloads", "17.4M", "Red");
+}
+
+#[test]
+fn endpoint() {
+  let url = "https://example.com/endpoint.json";
+  assert_badge(&format!("endpoint?url={url}"), "coverage", "98%", "Green");
+  assert_badge(&format!("endpoint?url={url}&label=cov&color=red"), "cov", "98%", "Red");
+
+  let url = "https://example.com/endpoint-error.json";
+  assert_badge(&format!("endpoint?url={url}"), "build", "failing", "Red");
+  assert_badge(&format!("endpoint?url={url}&color=blue&rcolor=blue"), "build", "failing", "Red");
+}
+
+#[test]
+fn dynamic() {
+  let base = "https://example.com";
+  let cases = [
+    ("json", "data.json", "$.version"),
+    ("yaml", "data.yaml", "$.version"),
+    ("toml", "data.toml", "$.package.version"),
+    ("xml", "data.xml", "//version"),
+  ];
+
+  for (kind, file, query) in cases {
+    let route = format!("dynamic/{kind}?url={base}/{file}&query={query}");
+    assert_badge(&route, "custom badge", "0.4.1", "DefaultValue");
+  }
+}
@@ -0,0 +1,71 @@
+// Prometheus metrics served at `/metrics`
+mod common;
+
+use std::time::Duration;
+
+use common::Server;
+
+async fn in_flight(server: &Server) -> String {
+  let text = server.get("/metrics").await.text().await.unwrap();
+  let line = text.lines().find(|x| x.starts_with("badges_http_requests_in_flight "));
+  line.unwrap().to_string()
+}
+
+// Value of `name{labels}` line, labels are sorted by name in output
+async fn metric(server: &Server, series: &str) -> Option<f64> {
+  let text = server.get("/metrics").await.text().await.unwrap();
+  let line = text.lines().find(|x| x.starts_with(&format!("{series} ")))?;
+  line.split(' ').next_back()?.parse().ok()
+}
+
+#[tokio::test]
+async fn requests() {
+  let server = Server::start().await;
+  let paths = [
+    "/crates/v/serde",
+    "/crates/v/status-404",
+    "/crates/v/status-500",
+    "/crates/v/status-404?strict=1",
+  ];
+  for path in paths {
+    server.get(path).await;
+  }
+
+  let route = r#"integration="crates",route="/crates/{kind}/{name}""#;
+  // upstream host is mock, as requests are rewritten to it
+  let upstream = r#"badges_upstream_request_duration_seconds_count{host="127.0.0.1""#;
+  let cases = [
+    (format!("badges_http_requests_total{{{route},status=\"200\"}}"), 3.0),
+    (format!("badges_http_requests_total{{{route},status=\"404\"}}"), 1.0),
+    (format!("badges_http_request_duration_seconds_count{{{route}}}"), 4.0),
+    (r#"badges_error_badges_total{cause="not_found"}"#.into(), 2.0),
+    (r#"badges_error_badges_total{cause="upstream_status"}"#.into(), 1.0),
+    (r#"badges_cache_events_total{event="miss"}"#.into(), 4.0),
+    (format!("{upstream},status=\"200\"}}"), 1.0),
+    (format!("{upstream},status=\"404\"}}"), 2.0),
+    (format!("{upstream},status=\"500\"}}"), 1.0),
+  ];
+
+  for (series, value) in cases {
+    assert_eq!(metric(&server, &series).await, Some(value), "{series}");
+  }
+
+  // latency is observed into buckets
+  let series = format!("badges_http_request_duration_seconds_bucket{{{route},le=\"+Inf\"}}");
+  assert_eq!(metric(&server, &series).await, Some(4.0));
+}
+
+#[tokio::test]
+async fn in_flight_cancelled() {
+  // mock responds in 5s and server waits for it, while client gives up in 200ms
+  let server = Server::start_with(&[("UPSTREAM_READ_TIMEOUT_MS", "10000")]).await;
+  let client = reqwest::Client::builder().timeout(Duration::from_millis(200)).build().unwrap();
+  for _ in 0..3 {
+    let rep = client.get(format!("{}/crates/v/slow", server.url)).send().await;
+    assert!(rep.is_err());
+  }
+
+  // dropped requests are not counted, while upstream would still answer in ~4s
+  tokio::time::sleep(Duration::from_millis(200)).await;
+  assert_eq!(in_flight(&server).await, "badges_http_requests_in_flight 0");
+}
@@ -0,0 +1,154 @@
+// Rendered SVGs are compared with files in `tests/snapshots`, to accept changes run:
+// `UPDATE_SNAPSHOTS=1 cargo test --test snapshots` and review the diff
+mod common;
+
+#[path = "../src/debug_items.rs"]
+mod debug_items;
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use debug_items::DEBUG_ITEMS;
+
+const STYLES: &[&str] = &["flat", "flat-square", "plastic", "for-the-badge", "social"];
+
+// Layout branches of `Badge::to_str`, rendered in each style
+const CASES: &[&str] = &[
+  "/badge/build-passing-green",
+  "/badge/passing-green",
+  "/badge/--green",
+  "/badge/-green?logo=rust",
+  "/badge/-green?logo=rust&labelColor=grey",
+  "/badge/-red?logo=wechat&logoColor=blue&labelColor=red",
+  "/badge/-07C160?logo=wechat&logoColor=white&label=Label",
+  "/badge/Rust-000000?logo=rust&logoColor=white",
+  "/badge/Value-Value-red?labelColor=red",
+  "/badge/build-passing-green?logo=github",
+  "/badge/stars-1.2k-blue?logo=github",
+  "/badge/build-passing-green/coverage-92%25-yellow/docs-blue",
+  "/badge?label=build&value=passing&color=green&segments=coverage,92%25:yellow",
+  "/badge/build-passing-green?radius=0",
+  "/badge/build-passing-green?radius=10",
+  "/badge/build-passing-green?scale=2",
+  "/badge/%F0%9F%93%A6%F0%9F%9A%80-semantic--release-e10079",
+  "/pypi/dm/twscrape",
+];
+
+// Simple-icons table is generated per build, so every logo is served as this icon from `ICONS_DIR`
+const ICON: &str = concat!(
+  r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24">"#,
+  r#"<path d="M2 2h20v8H12v12H2z"/></svg>"#,
+);
+
+fn snapshots_dir() -> PathBuf {
+  PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots")
+}
+
+// `/badge/Value-red?style=flat` -> `badge_Value-red_style_flat.svg`
+fn snapshot_name(route: &str) -> String {
+  let name: String = route
+    .trim_start_matches('/')
+    .chars()
+    .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '.' { c } else { '_' })
+    .collect();
+  format!("{}.svg", name.trim_matches('_'))
+}
+
+fn routes() -> Vec<String> {
+  let mut routes: Vec<String> = DEBUG_ITEMS.iter().map(|x| x.to_string()).collect();
+  for case in CASES {
+    for style in STYLES {
+      let sep = if case.contains('?') { '&' } else { '?' };
+      routes.push(format!("{case}{sep}style={style}"));
+    }
+  }
+  routes
+}
+
+// `{logo}.svg` for each route in temp dir, custom icons are looked up before simple-icons
+fn icons_dir(routes: &[String]) -> PathBuf {
+  let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("snapshot-icons");
+  let _ = std::fs::remove_dir_all(&dir);
+  std::fs::create_dir_all(&dir).unwrap();
+
+  for route in routes {
+    let url = reqwest::Url::parse(&format!("http://localhost{route}")).unwrap();
+    for (_, name) in url.query_pairs().filter(|(k, _)| k == "logo" || k == "icon") {
+      std::fs::write(dir.join(format!("{}.svg", name.to_lowercase())), ICON).unwrap();
+    }
+  }
+  dir
+}
+
+// Routes are rendered in parallel, as each one is separate `badges fetch` run
+fn render_all(routes: &[String]) -> BTreeMap<String, Result<Vec<u8>, String>> {
+  let icons = icons_dir(routes);
+  let envs = &[("ICONS_DIR", icons.to_str().unwrap())];
+  let chunk = routes.len().div_ceil(8);
+  std::thread::scope(|s| {
+    let tasks: Vec<_> = routes
+      .chunks(chunk)
+      .map(|chunk| {
+        s.spawn(move || {
+          chunk
+            .iter()
+            .map(|x| (x.clone(), common::fetch_raw_with(x, "svg", envs)))
+            .collect::<Vec<_>>()
+        })
+      })
+      .collect();
+
+    tasks.into_iter().flat_map(|x| x.join().unwrap()).collect()
+  })
+}
+
+#[test]
+fn svg_snapshots() {
+  let update = std::env::var("UPDATE_SNAPSHOTS").is_ok_and(|x| !x.is_empty() && x != "0");
+  let dir = snapshots_dir();
+
+  let mut files = BTreeMap::new();
+  for (route, rs) in render_all(&routes()) {
+    let svg = rs.unwrap_or_else(|e| panic!("{route}: {e}"));
+    let name = snapshot_name(&route);
+    if let Some(prev) = files.insert(name.clone(), (route.clone(), svg)) {
+      assert_eq!(prev.0, route, "snapshot name collision: {name}");
+    }
+  }
+
+  if update {
+    std::fs::create_dir_all(&dir).unwrap();
+    for entry in std::fs::read_dir(&dir).unwrap() {
+      let path = entry.unwrap().path();
+      let name = path.file_name().unwrap().to_string_lossy().to_string();
+      if !files.contains_key(&name) {
+        std::fs::remove_file(&path).unwrap();
+      }
+    }
+
+    for (name, (_, svg)) in &files {
+      std::fs::write(dir.join(name), svg).unwrap();
+    }
+    return;
+  }
+
+  let mut failed = vec![];
+  for (name, (route, svg)) in &files {
+    match std::fs::read(dir.join(name)) {
+      Ok(prev) if &prev == svg => {}
+      Ok(_) => failed.push(format!("{route}: changed ({name})")),
+      Err(_) => failed.push(format!("{route}: no snapshot ({name})")),
+    }
+  }
+
+  let count = std::fs::read_dir(&dir).map(|x| x.count()).unwrap_or(0);
+  if count > files.len() {
+    failed.push(format!("{} unused snapshots", count - files.len()));
+  }
+
+  assert!(
+    failed.is_empty(),
+    "snapshots are outdated, run with UPDATE_SNAPSHOTS=1 to accept:\n  {}",
+    failed.join("\n  ")
+  );
+}